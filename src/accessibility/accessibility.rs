@@ -0,0 +1,62 @@
+use crate::constants::attempts::SIX;
+use crate::gibbet_stage;
+use crate::logger::logger::Logger;
+
+pub const BODY_PARTS: [&str; 6] = ["head", "right arm", "left arm", "stem", "right leg", "left leg"];
+
+pub fn join_words(words: &[String]) -> String {
+    match words {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+pub fn describe_gibbet(attempts: u8) -> String {
+    let drawn = (attempts as usize).min(BODY_PARTS.len());
+    if drawn == 0 {
+        return format!("0 of {SIX} body parts drawn. The gibbet is empty.");
+    }
+    let parts: Vec<String> = BODY_PARTS[..drawn].iter().map(|part| part.to_string()).collect();
+    format!("{drawn} of {SIX} body parts drawn: {}.", join_words(&parts))
+}
+
+pub fn spell_word(formed_word_by_hits: &[String]) -> String {
+    let letters: Vec<String> = formed_word_by_hits.iter().enumerate().map(|(index, letter)| {
+        if letter.trim().is_empty() {
+            format!("{} blank", index + 1)
+        } else {
+            format!("{} {}", index + 1, letter.to_uppercase())
+        }
+    }).collect();
+    format!("The word has {} letters: {}.", letters.len(), letters.join(", "))
+}
+
+pub fn describe_positions(positions: &[usize]) -> String {
+    let numbers: Vec<String> = positions.iter().map(|index| (index + 1).to_string()).collect();
+    match numbers.len() {
+        1 => format!("position {}", numbers[0]),
+        _ => format!("positions {}", join_words(&numbers)),
+    }
+}
+
+pub fn announce_hit(letter: &str, positions: &[usize]) -> String {
+    format!("Correct! The letter {} is at {}.", letter.to_uppercase(), describe_positions(positions))
+}
+
+pub fn announce_miss(letter: &str, attempts: u8, lives: u8) -> String {
+    let remaining = lives.saturating_sub(attempts);
+    let noun = if remaining == 1 { "attempt" } else { "attempts" };
+    format!("Wrong. There is no letter {} in the word. {} {remaining} {noun} left.", letter.to_uppercase(), describe_gibbet(gibbet_stage(attempts, lives)))
+}
+
+pub fn print_accessible_game(formed_word_by_hits: &[String], errors: &[String], stage: u8, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", describe_gibbet(stage)));
+    logger.print(format_args!("{}", spell_word(formed_word_by_hits)));
+    if errors.is_empty() {
+        logger.print(format_args!("No wrong letters yet."));
+    } else {
+        let errors: Vec<String> = errors.iter().map(|letter| letter.to_uppercase()).collect();
+        logger.print(format_args!("Wrong letters: {}.", errors.join(", ")));
+    }
+}
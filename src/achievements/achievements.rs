@@ -0,0 +1,210 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::game::game::{GameStatus, HangmanGame};
+use crate::leaderboard::leaderboard::{clean_name, same_player, ScoreEntry};
+use crate::logger::logger::Logger;
+use crate::random_word::word_list::Word;
+use crate::stats::stats::data_dir;
+use crate::survival::survival::SURVIVAL_CATEGORY;
+
+pub const ACHIEVEMENTS_FILE: &str = "achievements.csv";
+pub const STREAK_GOAL: u32 = 10;
+pub const LONG_WORD: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Achievement {
+    Flawless,
+    LastLife,
+    Streak,
+    LongWord,
+    Category(String),
+}
+
+/// The achievements that do not depend on a category, in the order of the trophy screen.
+pub const ACHIEVEMENTS: [Achievement; 4] = [Achievement::Flawless, Achievement::LastLife, Achievement::Streak, Achievement::LongWord];
+
+impl Achievement {
+    pub fn title(&self) -> String {
+        match self {
+            Achievement::Flawless => "Flawless".to_string(),
+            Achievement::LastLife => "Close call".to_string(),
+            Achievement::Streak => "On fire".to_string(),
+            Achievement::LongWord => "Lexicographer".to_string(),
+            Achievement::Category(category) => format!("Master of {category}"),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Achievement::Flawless => "win without a miss or a hint".to_string(),
+            Achievement::LastLife => "win with one life left".to_string(),
+            Achievement::Streak => format!("win {STREAK_GOAL} games in a row"),
+            Achievement::LongWord => format!("solve a word of {LONG_WORD} letters or more"),
+            Achievement::Category(category) => format!("solve every word of the {category} category"),
+        }
+    }
+}
+
+impl fmt::Display for Achievement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Achievement::Flawless => write!(f, "flawless"),
+            Achievement::LastLife => write!(f, "last_life"),
+            Achievement::Streak => write!(f, "streak"),
+            Achievement::LongWord => write!(f, "long_word"),
+            Achievement::Category(category) => write!(f, "category:{category}"),
+        }
+    }
+}
+
+impl FromStr for Achievement {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "flawless" => Ok(Achievement::Flawless),
+            "last_life" => Ok(Achievement::LastLife),
+            "streak" => Ok(Achievement::Streak),
+            "long_word" => Ok(Achievement::LongWord),
+            _ => match value.strip_prefix("category:") {
+                Some(category) if !category.is_empty() => Ok(Achievement::Category(category.to_string())),
+                _ => Err(format!("unknown achievement `{value}`")),
+            },
+        }
+    }
+}
+
+/// An achievement a player unlocked, as stored next to the profiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unlock {
+    pub timestamp: u64,
+    pub player: String,
+    pub achievement: Achievement,
+}
+
+impl Unlock {
+    pub fn new(timestamp: u64, player: &str, achievement: Achievement) -> Unlock {
+        Unlock { timestamp, player: clean_name(player), achievement }
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{},{},{}", self.timestamp, self.player, self.achievement)
+    }
+
+    pub fn from_line(line: &str) -> Option<Unlock> {
+        let columns: Vec<&str> = line.trim().split(',').collect();
+        let [timestamp, player, achievement] = columns.as_slice() else {
+            return None;
+        };
+        Some(Unlock { timestamp: timestamp.parse().ok()?, player: player.to_string(), achievement: achievement.parse().ok()? })
+    }
+}
+
+/// What a player did before the current game, so it can tell which achievements the game unlocks.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AchievementTracker {
+    /// Achievements the player already has.
+    pub unlocked: Vec<Achievement>,
+    /// Games won in a row before this one.
+    pub streak: u32,
+    pub category: String,
+    /// Every word of the category in the word list, lowercase.
+    pub category_words: Vec<String>,
+    /// Words of the category the player solved before, lowercase.
+    pub solved: Vec<String>,
+    newly_unlocked: Vec<Achievement>,
+}
+
+impl AchievementTracker {
+    /// Gathers the history of `player` from their scores and unlocks, for a game of `word`.
+    pub fn from_history(player: &str, entries: &[ScoreEntry], unlocks: &[Unlock], word: &Word, words: &[Word]) -> AchievementTracker {
+        let player = clean_name(player);
+        // Survival runs span several words, they are not games of their own.
+        let games: Vec<&ScoreEntry> = entries.iter().filter(|entry| same_player(&entry.player, &player) && entry.category != SURVIVAL_CATEGORY).collect();
+        AchievementTracker {
+            unlocked: unlocks.iter().filter(|unlock| same_player(&unlock.player, &player)).map(|unlock| unlock.achievement.clone()).collect(),
+            streak: games.iter().rev().take_while(|entry| entry.won).count() as u32,
+            category: word.category.clone(),
+            category_words: words.iter().filter(|other| other.category.eq_ignore_ascii_case(&word.category)).map(|other| other.text.to_lowercase()).collect(),
+            solved: games.iter().filter(|entry| entry.won && entry.category.eq_ignore_ascii_case(&word.category)).map(|entry| entry.word.clone()).collect(),
+            newly_unlocked: Vec::new(),
+        }
+    }
+
+    /// Every achievement the finished game qualifies for, whether the player had it already or not.
+    pub fn earned(&self, game: &HangmanGame) -> Vec<Achievement> {
+        if game.status() != GameStatus::Won {
+            return Vec::new();
+        }
+        let word = game.word().to_lowercase();
+        let category_done = !self.category.is_empty()
+            && self.category_words.contains(&word)
+            && self.category_words.iter().all(|other| *other == word || self.solved.contains(other));
+        [
+            (game.attempts() == 0 && game.hints_used() == 0).then_some(Achievement::Flawless),
+            (game.lives().saturating_sub(game.attempts()) == 1).then_some(Achievement::LastLife),
+            (self.streak + 1 >= STREAK_GOAL).then_some(Achievement::Streak),
+            (word.chars().count() >= LONG_WORD).then_some(Achievement::LongWord),
+            category_done.then(|| Achievement::Category(self.category.to_lowercase())),
+        ].into_iter().flatten().collect()
+    }
+
+    /// Remembers the achievements the game unlocked for the first time and returns them.
+    pub fn check(&mut self, game: &HangmanGame) -> Vec<Achievement> {
+        let new: Vec<Achievement> = self.earned(game).into_iter().filter(|achievement| !self.unlocked.contains(achievement)).collect();
+        self.unlocked.extend(new.iter().cloned());
+        self.newly_unlocked.extend(new.iter().cloned());
+        new
+    }
+
+    pub fn newly_unlocked(&self) -> &[Achievement] {
+        &self.newly_unlocked
+    }
+}
+
+pub fn achievements_path() -> PathBuf {
+    data_dir().join(ACHIEVEMENTS_FILE)
+}
+
+pub fn record_unlocks(path: &Path, unlocks: &[Unlock]) -> Result<(), Error> {
+    if unlocks.is_empty() {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for unlock in unlocks.iter() {
+        writeln!(file, "{}", unlock.to_line())?;
+    }
+    Ok(())
+}
+
+pub fn load_unlocks(path: &Path) -> Result<Vec<Unlock>, Error> {
+    match read_to_string(path) {
+        Ok(content) => Ok(content.lines().filter_map(Unlock::from_line).collect()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn print_toast(achievement: &Achievement, logger: &mut dyn Logger) {
+    logger.print(format_args!("*** Achievement unlocked: {} - {} ***", achievement.title(), achievement.description()));
+}
+
+/// Every achievement with a mark on the unlocked ones, followed by the categories the player finished.
+pub fn print_trophies(player: &str, unlocks: &[Unlock], logger: &mut dyn Logger) {
+    let unlocked: Vec<&Achievement> = unlocks.iter().filter(|unlock| same_player(&unlock.player, player)).map(|unlock| &unlock.achievement).collect();
+    logger.print(format_args!("Trophies of {player} ({} unlocked)", unlocked.len()));
+    for achievement in ACHIEVEMENTS.iter() {
+        let mark = if unlocked.contains(&achievement) { "x" } else { " " };
+        logger.print(format_args!("[{mark}] {} - {}", achievement.title(), achievement.description()));
+    }
+    for achievement in unlocked.iter().filter(|achievement| matches!(achievement, Achievement::Category(_))) {
+        logger.print(format_args!("[x] {} - {}", achievement.title(), achievement.description()));
+    }
+}
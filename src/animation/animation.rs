@@ -0,0 +1,157 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::clear_screen;
+use crate::logger::logger::Logger;
+use crate::puppet::gibbet::Gibbet;
+
+pub const STROKE_DELAY: Duration = Duration::from_millis(60);
+pub const SWING_DELAY: Duration = Duration::from_millis(180);
+pub const CELEBRATION_DELAY: Duration = Duration::from_millis(220);
+
+const POLE: &str = "    |";
+const SWING_OFFSETS: [i8; 8] = [1, 0, -1, 0, 1, 0, -1, 0];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub content: String,
+    pub delay: Duration,
+}
+
+impl Frame {
+    pub fn new(content: String, delay: Duration) -> Frame {
+        Frame { content, delay }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameScheduler {
+    enabled: bool,
+    speed: f64,
+}
+
+impl Default for FrameScheduler {
+    fn default() -> Self {
+        FrameScheduler::new(true, 1.0)
+    }
+}
+
+impl FrameScheduler {
+    pub fn new(enabled: bool, speed: f64) -> FrameScheduler {
+        FrameScheduler { enabled, speed }
+    }
+
+    pub fn disabled() -> FrameScheduler {
+        FrameScheduler::new(false, 1.0)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn delay_of(&self, frame: &Frame) -> Duration {
+        if self.speed <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(frame.delay.as_secs_f64() / self.speed)
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn play(&self, frames: &[Frame], logger: &mut dyn Logger) {
+        if !self.enabled {
+            return;
+        }
+        for frame in frames.iter() {
+            clear_screen();
+            logger.print(format_args!("{}", frame.content));
+            sleep(self.delay_of(frame));
+        }
+    }
+}
+
+fn to_grid(stage: &str) -> Vec<Vec<char>> {
+    stage.split('\n').map(|line| line.chars().collect()).collect()
+}
+
+fn from_grid(grid: &[Vec<char>]) -> String {
+    grid.iter().map(|line| line.iter().collect::<String>()).collect::<Vec<String>>().join("\n")
+}
+
+pub fn get_strokes(from: &str, to: &str) -> Vec<(usize, usize, char)> {
+    let from: Vec<Vec<char>> = to_grid(from);
+    let to: Vec<Vec<char>> = to_grid(to);
+    let mut strokes: Vec<(usize, usize, char)> = Vec::new();
+    for (row, line) in to.iter().enumerate() {
+        for (column, character) in line.iter().enumerate() {
+            let previous = from.get(row).and_then(|line| line.get(column)).unwrap_or(&' ');
+            if !character.is_whitespace() && character != previous {
+                strokes.push((row, column, *character));
+            }
+        }
+    }
+    strokes
+}
+
+pub fn draw_limb(previous_attempts: u8, attempts: u8) -> Vec<Frame> {
+    let (Some(from), Some(to)) = (Gibbet::from_attempts(previous_attempts), Gibbet::from_attempts(attempts)) else {
+        return Vec::new();
+    };
+    let from: String = from.to_string();
+    let to: String = to.to_string();
+    let mut grid: Vec<Vec<char>> = to_grid(&from);
+    let mut frames: Vec<Frame> = Vec::new();
+    for (row, column, character) in get_strokes(&from, &to) {
+        while grid.len() <= row {
+            grid.push(Vec::new());
+        }
+        while grid[row].len() <= column {
+            grid[row].push(' ');
+        }
+        grid[row][column] = character;
+        frames.push(Frame::new(from_grid(&grid), STROKE_DELAY));
+    }
+    frames.push(Frame::new(to, STROKE_DELAY));
+    frames
+}
+
+pub fn shift_line(line: &str, offset: i8) -> String {
+    let Some(body) = line.strip_prefix(POLE) else {
+        return line.to_string();
+    };
+    match offset {
+        1.. => format!("{POLE}{}{body}", " ".repeat(offset as usize)),
+        ..=-1 => {
+            let removable = body.chars().take_while(|c| *c == ' ').count().min(offset.unsigned_abs() as usize);
+            format!("{POLE}{}", &body[removable..])
+        }
+        0 => line.to_string(),
+    }
+}
+
+pub fn swing() -> Vec<Frame> {
+    let stage: String = Gibbet::HeadRightArmLeftArmStemRightLegAndLeftLeg.to_string();
+    let lines: Vec<&str> = stage.split('\n').collect();
+    let head = lines.iter().position(|line| line.contains('⚉')).unwrap_or(lines.len());
+    SWING_OFFSETS.iter().map(|offset| {
+        let content = lines.iter().enumerate().map(|(index, line)| {
+            if index >= head && index < head + 3 {
+                shift_line(line, *offset)
+            } else {
+                line.to_string()
+            }
+        }).collect::<Vec<String>>().join("\n");
+        Frame::new(content, SWING_DELAY)
+    }).collect()
+}
+
+pub fn celebrate(word: &str) -> Vec<Frame> {
+    let poses = [
+        ["  *    .    *  ", "      \\o/      ", "       |       ", "      / \\      "],
+        ["  .    *    .  ", "      _o_      ", "       |       ", "      / \\      "],
+    ];
+    (0..6).map(|index| {
+        let pose = poses[index % poses.len()];
+        let content = format!("\n{}\n\n      {}\n", pose.join("\n"), word.to_uppercase());
+        Frame::new(content, CELEBRATION_DELAY)
+    }).collect()
+}
@@ -0,0 +1,156 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+#[cfg(feature = "terminal")]
+use std::io::stdin;
+use std::time::Duration;
+
+#[cfg(feature = "terminal")]
+use crate::get_answer;
+use crate::achievements::achievements::Achievement;
+use crate::logger::logger::Logger;
+use crate::timer::timer::Poll;
+#[cfg(feature = "terminal")]
+use crate::timer::timer::{LineReader, TICK};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    #[cfg(feature = "terminal")]
+    Interactive,
+    #[cfg(feature = "terminal")]
+    Stdin,
+    Lines(VecDeque<String>),
+    #[cfg(feature = "terminal")]
+    Timed(LineReader),
+}
+
+impl Default for Input {
+    #[cfg(feature = "terminal")]
+    fn default() -> Self {
+        Input::Interactive
+    }
+
+    #[cfg(not(feature = "terminal"))]
+    fn default() -> Self {
+        Input::Lines(VecDeque::new())
+    }
+}
+
+impl Input {
+    pub fn from_script(content: &str) -> Input {
+        Input::Lines(read_guesses(content))
+    }
+
+    #[cfg(feature = "terminal")]
+    pub fn is_interactive(&self) -> bool {
+        match self {
+            Input::Interactive => true,
+            Input::Timed(reader) => reader.is_interactive(),
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "terminal")]
+    pub fn is_timed(&self) -> bool {
+        matches!(self, Input::Timed(_))
+    }
+
+    #[cfg(not(feature = "terminal"))]
+    pub fn is_timed(&self) -> bool {
+        false
+    }
+
+    #[cfg(not(feature = "terminal"))]
+    pub fn is_interactive(&self) -> bool {
+        false
+    }
+
+    pub fn next_line(&mut self) -> Option<String> {
+        match self {
+            #[cfg(feature = "terminal")]
+            Input::Interactive => {
+                let answer = get_answer();
+                if answer.is_empty() {
+                    return None;
+                }
+                Some(answer.trim_end_matches(['\r', '\n']).to_string())
+            }
+            #[cfg(feature = "terminal")]
+            Input::Stdin => read_line(stdin().lock()),
+            Input::Lines(lines) => lines.pop_front(),
+            #[cfg(feature = "terminal")]
+            Input::Timed(reader) => loop {
+                match reader.poll(TICK) {
+                    Poll::Line(line) => return Some(line),
+                    Poll::Closed => return None,
+                    Poll::Pending => (),
+                }
+            },
+        }
+    }
+
+    /// Waits at most `timeout` for a line when the input is timed, the other inputs answer right away.
+    #[cfg_attr(not(feature = "terminal"), allow(unused_variables))]
+    pub fn poll_line(&mut self, timeout: Duration) -> Poll {
+        match self {
+            #[cfg(feature = "terminal")]
+            Input::Timed(reader) => reader.poll(timeout),
+            _ => match self.next_line() {
+                Some(line) => Poll::Line(line),
+                None => Poll::Closed,
+            },
+        }
+    }
+}
+
+pub fn read_line<R: BufRead>(mut reader: R) -> Option<String> {
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+    }
+}
+
+pub fn read_guesses(content: &str) -> VecDeque<String> {
+    content.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+pub fn masked_word(formed_word_by_hits: &[String]) -> String {
+    formed_word_by_hits.iter().map(|letter| if letter.trim().is_empty() { "_".to_string() } else { letter.clone() }).collect()
+}
+
+pub fn format_state(formed_word_by_hits: &[String], errors: &[String], attempts: u8, lives: u8) -> String {
+    let word = masked_word(formed_word_by_hits);
+    format!("state word={word} misses={} lives={}/{lives}", errors.join(","), lives.saturating_sub(attempts))
+}
+
+pub fn print_state(formed_word_by_hits: &[String], errors: &[String], attempts: u8, lives: u8, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", format_state(formed_word_by_hits, errors, attempts, lives)));
+}
+
+pub fn print_guess(letter: &str, hit: bool, logger: &mut dyn Logger) {
+    logger.print(format_args!("guess {} {}", letter.to_lowercase(), if hit { "hit" } else { "miss" }));
+}
+
+pub fn print_hint(letter: &str, logger: &mut dyn Logger) {
+    logger.print(format_args!("hint {}", letter.to_lowercase()));
+}
+
+pub fn print_achievement(achievement: &Achievement, logger: &mut dyn Logger) {
+    logger.print(format_args!("achievement {achievement}"));
+}
+
+pub fn print_undo(undone: bool, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", if undone { "undo" } else { "undo unavailable" }));
+}
+
+pub fn print_timeout(logger: &mut dyn Logger) {
+    logger.print(format_args!("timeout"));
+}
+
+pub fn print_result(result: &str, word: &str, logger: &mut dyn Logger) {
+    logger.print(format_args!("result {result} word={}", word.to_lowercase()));
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::io::Error;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::batch::batch::masked_word;
+use crate::bot::transport::{ChatMessage, Transport};
+use crate::compact_gibbet;
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::random_word::word_list::{choose_word, Word, WordFilter};
+
+pub const DEFAULT_PREFIX: &str = "!";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BotCommand {
+    Start,
+    Stop,
+    Show,
+    Help,
+    Guess(String),
+    Hint,
+}
+
+/// Plays one game per channel, answering `!hangman start`, `!guess e` and friends.
+#[derive(Debug)]
+pub struct Bot {
+    words: Vec<Word>,
+    filter: WordFilter,
+    lives: u8,
+    prefix: String,
+    rng: StdRng,
+    games: HashMap<String, HangmanGame>,
+}
+
+pub fn parse_command(text: &str, prefix: &str) -> Option<BotCommand> {
+    let text = text.trim().strip_prefix(prefix)?;
+    let mut parts = text.split_whitespace();
+    let command = parts.next()?.to_lowercase();
+    let argument = parts.next().map(|argument| argument.to_lowercase());
+    match (command.as_str(), argument.as_deref()) {
+        ("hangman" | "forca", Some("start")) => Some(BotCommand::Start),
+        ("hangman" | "forca", Some("stop")) => Some(BotCommand::Stop),
+        ("hangman" | "forca", Some("help")) => Some(BotCommand::Help),
+        ("hangman" | "forca", None) => Some(BotCommand::Show),
+        ("guess", Some(guess)) => Some(BotCommand::Guess(guess.to_string())),
+        ("hint", None) => Some(BotCommand::Hint),
+        _ => None,
+    }
+}
+
+/// The word with spaces between the letters, the misses and a one-line gibbet.
+pub fn format_compact(game: &HangmanGame) -> String {
+    let snapshot = game.snapshot();
+    let word: Vec<String> = masked_word(&snapshot.formed_word_by_hits).chars().map(|letter| letter.to_uppercase().to_string()).collect();
+    let misses = if snapshot.errors.is_empty() { "-".to_string() } else { snapshot.errors.join(" ").to_uppercase() };
+    format!("{} | misses: {misses} | {}", word.join(" "), compact_gibbet(snapshot.attempts, snapshot.lives))
+}
+
+impl Bot {
+    pub fn new(words: Vec<Word>, filter: WordFilter, lives: u8, seed: Option<u64>) -> Bot {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Bot { words, filter, lives, prefix: DEFAULT_PREFIX.to_string(), rng, games: HashMap::new() }
+    }
+
+    pub fn with_prefix(mut self, prefix: &str) -> Bot {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    pub fn game(&self, channel: &str) -> Option<&HangmanGame> {
+        self.games.get(channel)
+    }
+
+    fn help(&self) -> String {
+        let prefix = &self.prefix;
+        format!("{prefix}hangman start | {prefix}guess <letter or word> | {prefix}hint | {prefix}hangman (show) | {prefix}hangman stop")
+    }
+
+    /// The reply to a chat message, or `None` when the message is not meant for the bot.
+    pub fn handle(&mut self, message: &ChatMessage) -> Option<String> {
+        let command = parse_command(&message.text, &self.prefix)?;
+        let channel = &message.channel;
+        let reply = match (command, self.games.get_mut(channel)) {
+            (BotCommand::Help, _) => self.help(),
+            (BotCommand::Start, Some(game)) => format!("A game is already running: {}", format_compact(game)),
+            (BotCommand::Start, None) => {
+                let Some(word) = choose_word(&self.words, &self.filter, &mut self.rng) else {
+                    return Some("I have no words to play with.".to_string());
+                };
+                let game = HangmanGame::headless(&word.text, self.lives);
+                let reply = format!("New game! {}", format_compact(&game));
+                self.games.insert(channel.clone(), game);
+                reply
+            }
+            (_, None) => format!("No game here, start one with {}hangman start", self.prefix),
+            (BotCommand::Show, Some(game)) => format_compact(game),
+            (BotCommand::Stop, Some(game)) => {
+                let reply = format!("Game stopped, the word was {}.", game.word().to_uppercase());
+                self.games.remove(channel);
+                reply
+            }
+            (BotCommand::Hint, Some(game)) => match game.use_hint() {
+                Some(letter) => format!("{} used a hint: {}. {}", message.sender, letter.to_uppercase(), format_compact(game)),
+                None => "No hint left.".to_string(),
+            },
+            (BotCommand::Guess(guess), Some(_)) if !guess.chars().all(char::is_alphabetic) => {
+                format!("{}: guess letters only, {}guess <letter or word>", message.sender, self.prefix)
+            }
+            (BotCommand::Guess(guess), Some(game)) => {
+                let snapshot = game.snapshot();
+                if snapshot.hits.contains(&guess) || snapshot.errors.contains(&guess) {
+                    return Some(format!("{} was already guessed.", guess.to_uppercase()));
+                }
+                let attempts = game.attempts();
+                game.verify_answer(guess.clone());
+                let result = match (guess.chars().count() > 1, game.attempts() == attempts) {
+                    (false, true) => format!("{}: {} is in the word!", message.sender, guess.to_uppercase()),
+                    (false, false) => format!("{}: no {}.", message.sender, guess.to_uppercase()),
+                    (true, _) => format!("{}: it is not {}.", message.sender, guess.to_uppercase()),
+                };
+                match game.status() {
+                    GameStatus::InProgress => format!("{result} {}", format_compact(game)),
+                    GameStatus::Won => format!("{} solved it! The word was {}.", message.sender, game.word().to_uppercase()),
+                    GameStatus::Lost => format!("{result} Game over, the word was {}.", game.word().to_uppercase()),
+                }
+            }
+        };
+        if self.games.get(channel).is_some_and(|game| game.status() != GameStatus::InProgress) {
+            self.games.remove(channel);
+        }
+        Some(reply)
+    }
+
+    /// Answers messages until the transport is closed.
+    pub fn run(&mut self, transport: &mut dyn Transport) -> Result<(), Error> {
+        while let Some(message) = transport.receive() {
+            if let Some(reply) = self.handle(&message) {
+                transport.send(&message.channel, &reply)?;
+            }
+        }
+        Ok(())
+    }
+}
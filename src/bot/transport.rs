@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, Error, Write};
+
+use crate::batch::batch::read_line;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatMessage {
+    pub channel: String,
+    pub sender: String,
+    pub text: String,
+}
+
+/// Where the bot reads messages from and posts its replies, e.g. an IRC or Matrix connection.
+pub trait Transport {
+    /// Blocks until the next message arrives, `None` once the connection is closed.
+    fn receive(&mut self) -> Option<ChatMessage>;
+
+    fn send(&mut self, channel: &str, text: &str) -> Result<(), Error>;
+}
+
+impl ChatMessage {
+    pub fn new(channel: &str, sender: &str, text: &str) -> ChatMessage {
+        ChatMessage { channel: channel.to_string(), sender: sender.to_string(), text: text.to_string() }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    incoming: VecDeque<ChatMessage>,
+    sent: Vec<(String, String)>,
+}
+
+impl MockTransport {
+    pub fn new(incoming: Vec<ChatMessage>) -> MockTransport {
+        MockTransport { incoming: incoming.into(), sent: Vec::new() }
+    }
+
+    pub fn push(&mut self, message: ChatMessage) {
+        self.incoming.push_back(message);
+    }
+
+    pub fn sent(&self) -> &[(String, String)] {
+        &self.sent
+    }
+}
+
+impl Transport for MockTransport {
+    fn receive(&mut self) -> Option<ChatMessage> {
+        self.incoming.pop_front()
+    }
+
+    fn send(&mut self, channel: &str, text: &str) -> Result<(), Error> {
+        self.sent.push((channel.to_string(), text.to_string()));
+        Ok(())
+    }
+}
+
+/// Reads `channel sender text` lines and writes `channel text` lines, to drive the bot from a pipe.
+pub struct LineTransport<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+}
+
+pub fn parse_chat_line(line: &str) -> Option<ChatMessage> {
+    let mut parts = line.trim().splitn(3, char::is_whitespace);
+    let channel = parts.next().filter(|channel| !channel.is_empty())?;
+    let sender = parts.next()?;
+    let text = parts.next()?.trim();
+    Some(ChatMessage::new(channel, sender, text))
+}
+
+impl<R: BufRead, W: Write> LineTransport<R, W> {
+    pub fn new(reader: R, writer: W) -> LineTransport<R, W> {
+        LineTransport { reader, writer }
+    }
+
+    pub fn into_inner(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R: BufRead, W: Write> Transport for LineTransport<R, W> {
+    fn receive(&mut self) -> Option<ChatMessage> {
+        loop {
+            let line = read_line(&mut self.reader)?;
+            if let Some(message) = parse_chat_line(&line) {
+                return Some(message);
+            }
+        }
+    }
+
+    fn send(&mut self, channel: &str, text: &str) -> Result<(), Error> {
+        writeln!(self.writer, "{channel} {text}")?;
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,420 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::logger::logger::Level;
+use crate::random_word::validate::{MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+use crate::random_word::import::Format;
+use crate::random_word::word_list::{Difficulty, DEFAULT_CATEGORY};
+
+fn parse_speed(value: &str) -> Result<f64, String> {
+    let speed: f64 = value.parse().map_err(|_| format!("`{value}` is not a number"))?;
+    match speed.is_finite() && speed > 0.0 {
+        true => Ok(speed),
+        false => Err(format!("`{value}` is not a positive number")),
+    }
+}
+
+pub const EXIT_SUCCESS: u8 = 0;
+pub const EXIT_FAILURE: u8 = 1;
+pub const EXIT_ERROR: u8 = 2;
+
+#[derive(Debug, Parser)]
+#[command(name = "jogo_da_forca", version, about = "Hangman game (Jogo da Forca)")]
+pub struct Cli {
+    /// Describe the game in full sentences instead of drawing it
+    #[arg(long, global = true)]
+    pub accessible: bool,
+
+    /// Disable the gibbet animations
+    #[arg(long, global = true)]
+    pub no_animation: bool,
+
+    /// Configuration file applied on top of the user and project ones
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Append the game events to this file as JSON lines
+    #[arg(long, global = true, value_name = "PATH")]
+    pub event_log: Option<PathBuf>,
+
+    /// Write the game messages and diagnostics to this file
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Lowest level written to `--log-file` (debug, info, warn or error)
+    #[arg(long, global = true, default_value = "info")]
+    pub log_level: Level,
+
+    /// Name the scores are recorded under (defaults to the `player` setting, then the login name)
+    #[arg(long, global = true)]
+    pub player: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Play a game with a random word
+    Play(PlayArgs),
+    /// Show statistics of the games played
+    Stats,
+    /// Play the word of the day
+    Daily(DailyArgs),
+    /// Solve as many words as possible before the time runs out
+    Blitz(BlitzArgs),
+    /// Play word after word with lives carried over until the gibbet is complete
+    Survival(SurvivalArgs),
+    /// Host games over the network
+    Serve(ServeArgs),
+    /// List the words matching a pattern such as `_pp__`
+    Solve(SolveArgs),
+    /// Manage word lists
+    #[command(subcommand)]
+    Words(WordsCommand),
+    /// Replay a game recorded with `--event-log`
+    Replay(ReplayArgs),
+    /// Show the best players
+    Leaderboard(LeaderboardArgs),
+    /// Show the profile of a player
+    Profile(ProfileArgs),
+    /// Show the achievements of a player
+    Trophies(ProfileArgs),
+    /// Run the chat bot on `channel sender text` lines read from stdin
+    Bot(BotArgs),
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct PlayArgs {
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Only pick words from this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Only pick words of this difficulty (easy, medium or hard)
+    #[arg(long)]
+    pub difficulty: Option<Difficulty>,
+
+    /// Number of wrong guesses allowed
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub lives: Option<u8>,
+
+    /// Seed of the random word selection
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Unranked game where `:u` takes back the last guess
+    #[arg(long)]
+    pub practice: bool,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct DailyArgs {
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BlitzArgs {
+    /// Length of the blitz in minutes
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..))]
+    pub minutes: u64,
+
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Only pick words from this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Only pick words of this difficulty (easy, medium or hard)
+    #[arg(long)]
+    pub difficulty: Option<Difficulty>,
+
+    /// Number of wrong guesses allowed for each word
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub lives: Option<u8>,
+
+    /// Seed of the random word selection
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SurvivalArgs {
+    /// Lives of the whole run, a solved word gives one back
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub lives: Option<u8>,
+
+    /// Words solved before the difficulty goes up
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    pub ramp: u32,
+
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Only pick words from this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Seed of the random word selection
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct InputArgs {
+    /// Read the guesses from stdin, one per line, and print line-oriented output
+    #[arg(long)]
+    pub batch: bool,
+
+    /// Read the guesses from a file, one per line, and print line-oriented output
+    #[arg(long, value_name = "PATH", conflicts_with = "batch")]
+    pub guesses: Option<PathBuf>,
+
+    /// Seconds allowed for each guess, waiting longer costs a life
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub guess_time: Option<u64>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    pub address: String,
+
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Only pick words from this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Number of wrong guesses allowed
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub lives: Option<u8>,
+
+    /// Seed of the random word selection
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Seconds a player of a room has to guess before their turn is skipped
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    pub turn_timeout: u64,
+
+    /// Serve the JSON API over HTTP instead of the line protocol
+    #[arg(long, conflicts_with = "web")]
+    pub http: bool,
+
+    /// Serve a browser game over HTTP and WebSocket instead of the line protocol
+    #[arg(long)]
+    pub web: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SolveArgs {
+    /// Known letters with `_` for the unknown ones
+    pub pattern: String,
+
+    /// Letters already guessed that are not in the word
+    #[arg(long, default_value = "")]
+    pub misses: String,
+
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ReplayArgs {
+    /// Event log written with `--event-log`
+    pub path: PathBuf,
+
+    /// Game of the log to replay, starting at 1 (defaults to the last one)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub game: Option<u32>,
+
+    /// Playback speed multiplier
+    #[arg(long, default_value_t = 1.0, value_parser = parse_speed)]
+    pub speed: f64,
+
+    /// Start paused and step with `n`
+    #[arg(long)]
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct LeaderboardArgs {
+    /// Only count the games played since Monday
+    #[arg(long, conflicts_with = "category")]
+    pub weekly: bool,
+
+    /// Only count the games of this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Number of players shown
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct ProfileArgs {
+    /// Player to show (defaults to the current player)
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BotArgs {
+    /// File with one word per line, optionally followed by `,category` and `,weight`
+    #[arg(long, value_name = "PATH")]
+    pub word_list: Vec<PathBuf>,
+
+    /// Only pick words from this category
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Number of wrong guesses allowed
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub lives: Option<u8>,
+
+    /// Seed of the random word selection
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Language of the words
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Prefix of the bot commands
+    #[arg(long, default_value = "!")]
+    pub prefix: String,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WordsCommand {
+    /// Check a word list for mistakes
+    Validate {
+        /// Word list to check
+        path: PathBuf,
+
+        /// Language of the words, which sets the alphabet
+        #[arg(long)]
+        lang: Option<String>,
+
+        /// Letters allowed in the words (defaults to the alphabet of the language)
+        #[arg(long)]
+        alphabet: Option<String>,
+
+        /// Shortest word allowed
+        #[arg(long, default_value_t = MIN_WORD_LENGTH)]
+        min_length: usize,
+
+        /// Longest word allowed
+        #[arg(long, default_value_t = MAX_WORD_LENGTH)]
+        max_length: usize,
+
+        /// File with words that must not be in the list, one per line
+        #[arg(long, value_name = "PATH")]
+        blocklist: Vec<PathBuf>,
+    },
+    /// Compile word lists into an index that loads without parsing them (use it as a word list with an `.idx` extension)
+    Build {
+        /// Word lists to compile
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Language of the words
+        #[arg(long)]
+        lang: Option<String>,
+
+        /// Index file to write
+        #[arg(long, short, value_name = "PATH")]
+        output: PathBuf,
+    },
+    /// Build a word list from a hunspell `.dic` file, a plain list of words or a frequency list
+    Import {
+        /// Dictionary to read
+        path: PathBuf,
+
+        /// Format of the dictionary (hunspell, plain or frequency), guessed from the extension by default
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Hunspell `.aff` file to expand the affix flags with (they are dropped otherwise)
+        #[arg(long, value_name = "PATH")]
+        aff: Option<PathBuf>,
+
+        /// Language of the words, which sets the alphabet and the blocklist
+        #[arg(long)]
+        lang: Option<String>,
+
+        /// Category written next to every word
+        #[arg(long, default_value = DEFAULT_CATEGORY)]
+        category: String,
+
+        /// Shortest word kept
+        #[arg(long, default_value_t = MIN_WORD_LENGTH)]
+        min_length: usize,
+
+        /// Longest word kept
+        #[arg(long, default_value_t = MAX_WORD_LENGTH)]
+        max_length: usize,
+
+        /// Fewest occurrences of a word in a frequency list
+        #[arg(long)]
+        min_frequency: Option<u64>,
+
+        /// Most words kept, the most frequent ones first
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// File the word list is written to (defaults to the standard output)
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+}
@@ -0,0 +1,479 @@
+use std::fs::{read_to_string, write};
+use std::io::{stdin, stdout, BufReader, Error, ErrorKind, IsTerminal, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
+use tiny_http::Server;
+
+use crate::achievements::achievements::{achievements_path, load_unlocks, print_trophies, record_unlocks, AchievementTracker, Unlock};
+use crate::animation::animation::FrameScheduler;
+use crate::batch::batch::{read_guesses, Input};
+use crate::cli::cli::{BlitzArgs, BotArgs, Cli, Command, DailyArgs, InputArgs, LeaderboardArgs, PlayArgs, ProfileArgs, ReplayArgs, ServeArgs, SolveArgs, SurvivalArgs, WordsCommand, EXIT_ERROR, EXIT_FAILURE, EXIT_SUCCESS};
+use crate::bot::bot::Bot;
+use crate::bot::transport::LineTransport;
+use crate::config::config::Config;
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::leaderboard::leaderboard::{clean_name, load_profiles, load_scores, print_leaderboard, print_profile, profiles_path, record_score, same_player, scores_path, standings, update_profile, ScoreEntry, View};
+use crate::leaderboard::score::{Score, ScoreInput, ScoreRules};
+use crate::logger::event::now_millis;
+use crate::logger::json_lines::JsonLinesLogger;
+use crate::logger::logger::{Logger, StdoutLogger};
+use crate::logger::sinks::{FileLogger, LevelFilter, MultiLogger};
+use crate::random_word::blocklist::{load_blocklist, Blocklist, Blocklists};
+use crate::random_word::import::{format_word_list, import_words, parse_frequency, parse_hunspell, parse_plain, Affixes, Format, ImportFilter};
+use crate::random_word::index::{build_index, is_index, open_index};
+use crate::random_word::validate::{print_issues, validate_word_list, ValidationRules};
+use crate::random_word::word_list::{built_in_words, choose_word, daily_seed, load_word_list, Word, WordFilter};
+use crate::replay::replay::{build_steps, parse_event_log, run_replay, split_games, ReplayControls};
+use crate::server::http::{serve_http, Api};
+use crate::server::server::{serve as serve_games, ServerOptions};
+use crate::server::web::serve_web;
+use crate::solver::solver::print_solution;
+use crate::stats::stats::{load_records, now, print_summary, record_game, stats_path, GameRecord, Summary};
+use crate::survival::survival::{print_round, print_run_summary, SurvivalRun, SURVIVAL_CATEGORY};
+use crate::timer::timer::{print_blitz_summary, BlitzTally, Countdown, LineReader};
+
+pub fn load_words(word_lists: &[PathBuf], language: &str, blocklists: &Blocklists) -> Result<Vec<Word>, Error> {
+    load_words_matching(word_lists, &WordFilter { language: Some(language.to_string()), ..WordFilter::default() }, blocklists, None)
+}
+
+/// The words of the language and category of the filter and, when given, of `length` letters, the only ones indexes read.
+///
+/// The difficulty is left to `choose_word`, so the achievements still know every word of the category.
+/// Lists that do not exist are skipped with a warning, and the built-in words are used when none of them could be read.
+pub fn load_words_matching(word_lists: &[PathBuf], filter: &WordFilter, blocklists: &Blocklists, length: Option<usize>) -> Result<Vec<Word>, Error> {
+    let filter = WordFilter { difficulty: None, ..filter.clone() };
+    let matches = |word: &Word| filter.matches(word) && length.is_none_or(|length| word.text.chars().count() == length);
+    let language = filter.language.clone().unwrap_or_default();
+    let mut words: Vec<Word> = Vec::new();
+    let mut found = false;
+    for path in word_lists.iter() {
+        let loaded = if is_index(path) {
+            open_index(path).and_then(|index| match index.is_empty() {
+                true => Err(Error::new(ErrorKind::InvalidData, format!("{} has no words", path.display()))),
+                false => Ok(index.words(&filter, length)),
+            })
+        } else {
+            load_word_list(path, &language).map(|list| list.into_iter().filter(matches).collect())
+        };
+        match loaded {
+            Ok(loaded) => {
+                words.extend(loaded);
+                found = true;
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => eprintln!("warning: skipping word list {}: {error}", path.display()),
+            Err(error) => return Err(error),
+        }
+    }
+    if !found {
+        words = built_in_words().into_iter().filter(matches).collect();
+    }
+    Ok(blocklists.filter(words))
+}
+
+/// The built-in blocklists unless `family_friendly` is off, plus the files configured for each language.
+pub fn blocklists_of(config: &Config) -> Result<Blocklists, Error> {
+    let mut blocklists = if config.family_friendly { Blocklists::family_friendly() } else { Blocklists::default() };
+    for (language, paths) in config.blocklists.iter() {
+        for path in paths.iter() {
+            blocklists.add(language, load_blocklist(path)?);
+        }
+    }
+    Ok(blocklists)
+}
+
+pub fn word_lists_of(arguments: &[PathBuf], config: &Config) -> Vec<PathBuf> {
+    if arguments.is_empty() {
+        config.word_lists.clone()
+    } else {
+        arguments.to_vec()
+    }
+}
+
+/// Timed inputs read on a background thread, so the clock keeps running while the player types.
+pub fn input_of(args: &InputArgs, timed: bool) -> Result<Input, Error> {
+    match (&args.guesses, args.batch, timed) {
+        (Some(path), _, false) => Ok(Input::from_script(&read_to_string(path)?)),
+        (Some(path), _, true) => Ok(Input::Timed(LineReader::from_lines(read_guesses(&read_to_string(path)?)))),
+        (None, batch, true) => Ok(Input::Timed(LineReader::spawn(BufReader::new(stdin()), !batch))),
+        (None, true, false) => Ok(Input::Stdin),
+        (None, false, false) => Ok(Input::Interactive),
+    }
+}
+
+pub fn status_code(status: GameStatus) -> u8 {
+    match status {
+        GameStatus::Won => EXIT_SUCCESS,
+        GameStatus::Lost => EXIT_FAILURE,
+        GameStatus::InProgress => EXIT_ERROR,
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run(cli: Cli) -> u8 {
+    let config = match Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return EXIT_ERROR;
+        }
+    };
+    let result = match cli.command {
+        None => play(&cli, &config, &PlayArgs::default()),
+        Some(Command::Play(ref args)) => play(&cli, &config, args),
+        Some(Command::Daily(ref args)) => daily(&cli, &config, args),
+        Some(Command::Blitz(ref args)) => blitz(&cli, &config, args),
+        Some(Command::Survival(ref args)) => survival(&cli, &config, args),
+        Some(Command::Stats) => stats(),
+        Some(Command::Serve(ref args)) => serve(&cli, &config, args),
+        Some(Command::Solve(ref args)) => solve(&config, args),
+        Some(Command::Words(ref command)) => words(&config, command),
+        Some(Command::Replay(ref args)) => replay(args),
+        Some(Command::Leaderboard(ref args)) => leaderboard(args),
+        Some(Command::Profile(ref args)) => profile(&cli, &config, args),
+        Some(Command::Trophies(ref args)) => trophies(&cli, &config, args),
+        Some(Command::Bot(ref args)) => bot(&config, args),
+    };
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {error}");
+            EXIT_ERROR
+        }
+    }
+}
+
+pub fn logger_of(cli: &Cli) -> Result<Box<dyn Logger>, Error> {
+    let mut logger = MultiLogger::new().with(Box::new(StdoutLogger));
+    if let Some(path) = &cli.log_file {
+        logger.add_sink(Box::new(LevelFilter::new(cli.log_level, Box::new(FileLogger::create(path)?))));
+    }
+    Ok(Box::new(logger))
+}
+
+pub fn player_of(cli: &Cli, config: &Config) -> String {
+    cli.player.clone().unwrap_or_else(|| config.player_name())
+}
+
+#[cfg(not(tarpaulin_include))]
+fn new_game(cli: &Cli, config: &Config, word: &Word, words: &[Word], lives: Option<u8>, input: Input, guess_time: Option<u64>) -> Result<HangmanGame, Error> {
+    let mut game: HangmanGame = Game::new(&word.text, Vec::new(), Vec::new(), Vec::new(), 0);
+    game.set_lives(lives.unwrap_or(config.lives));
+    game.set_achievements(tracker_of(cli, config, word, words));
+    set_up_game(cli, config, game, input, guess_time)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn set_up_game(cli: &Cli, config: &Config, mut game: HangmanGame, input: Input, guess_time: Option<u64>) -> Result<HangmanGame, Error> {
+    if config.animations && !cli.no_animation && stdout().is_terminal() {
+        game.set_scheduler(FrameScheduler::default());
+    }
+    game.set_accessible(cli.accessible || config.accessible);
+    game.set_theme(config.theme);
+    game.set_sound(config.sound);
+    game.set_key_bindings(config.keys.clone());
+    game.set_batch(input);
+    game.set_guess_time(guess_time.map(Duration::from_secs));
+    game.set_logger(logger_of(cli)?);
+    if let Some(path) = &cli.event_log {
+        game.add_event_sink(Box::new(JsonLinesLogger::create(path)?));
+    }
+    Ok(game)
+}
+
+/// The history of the player, an unreadable one only means no achievement is unlocked by mistake twice.
+#[cfg(not(tarpaulin_include))]
+fn tracker_of(cli: &Cli, config: &Config, word: &Word, words: &[Word]) -> AchievementTracker {
+    let entries = load_scores(&scores_path()).unwrap_or_default();
+    let unlocks = load_unlocks(&achievements_path()).unwrap_or_default();
+    AchievementTracker::from_history(&player_of(cli, config), &entries, &unlocks, word, words)
+}
+
+/// Adds a finished game to the statistics, the leaderboard and the trophies, warning when they cannot be written.
+#[cfg(not(tarpaulin_include))]
+fn record_result(cli: &Cli, config: &Config, game: &HangmanGame, word: &Word) -> Score {
+    record_stats(game);
+    if game.is_practice() {
+        return Score::default();
+    }
+    let score = ScoreRules::default().score(&ScoreInput::of(game));
+    record_entry(&ScoreEntry::new(now(), &player_of(cli, config), game.word(), &word.category, game.status() == GameStatus::Won, &score));
+    let unlocks: Vec<Unlock> = game.unlocked_achievements().into_iter().map(|achievement| Unlock::new(now(), &player_of(cli, config), achievement)).collect();
+    if let Err(error) = record_unlocks(&achievements_path(), &unlocks) {
+        eprintln!("warning: could not record the achievements: {error}");
+    }
+    score
+}
+
+#[cfg(not(tarpaulin_include))]
+fn record_stats(game: &HangmanGame) {
+    let record = GameRecord::new(game.word(), game.status() == GameStatus::Won, game.attempts(), game.lives()).with_undos(game.undos());
+    if let Err(error) = record_game(&stats_path(), &record) {
+        eprintln!("warning: could not record statistics: {error}");
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn record_entry(entry: &ScoreEntry) {
+    if let Err(error) = record_score(&scores_path(), entry).and_then(|_| update_profile(&profiles_path(), entry)) {
+        eprintln!("warning: could not record the score: {error}");
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn play_word(cli: &Cli, config: &Config, word: &Word, words: &[Word], lives: Option<u8>, input: &InputArgs, practice: bool) -> Result<u8, Error> {
+    let mut game = new_game(cli, config, word, words, lives, input_of(input, input.guess_time.is_some())?, input.guess_time)?;
+    game.set_practice(practice);
+    game.play();
+    if game.status() == GameStatus::InProgress {
+        return Ok(EXIT_ERROR);
+    }
+    let score = record_result(cli, config, &game, word);
+    if !game.is_batch() && !practice {
+        StdoutLogger.print(format_args!("Score: {}", score.describe()));
+    }
+    Ok(status_code(game.status()))
+}
+
+#[cfg(not(tarpaulin_include))]
+fn play(cli: &Cli, config: &Config, args: &PlayArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { category: args.category.clone(), difficulty: args.difficulty.or(config.difficulty), language: Some(language) };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    let mut rng: Box<dyn RngCore> = match args.seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(thread_rng()),
+    };
+    let word = choose_word(&words, &filter, &mut rng)
+        .ok_or(Error::new(ErrorKind::NotFound, "no word matches the given options"))?;
+    play_word(cli, config, word, &words, args.lives, &args.input, args.practice)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn daily(cli: &Cli, config: &Config, args: &DailyArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { language: Some(language), ..WordFilter::default() };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    let mut rng = StdRng::seed_from_u64(daily_seed(now()));
+    let word = choose_word(&words, &filter, &mut rng)
+        .ok_or(Error::new(ErrorKind::NotFound, "no word available for this language"))?;
+    play_word(cli, config, word, &words, None, &args.input, false)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn blitz(cli: &Cli, config: &Config, args: &BlitzArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { category: args.category.clone(), difficulty: args.difficulty.or(config.difficulty), language: Some(language) };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    let mut rng: Box<dyn RngCore> = match args.seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(thread_rng()),
+    };
+    // Every word of the blitz reads from the same input, a line typed during one word must not be lost to the next.
+    let input = input_of(&args.input, true)?;
+    let deadline = Countdown::new(Duration::from_secs(args.minutes.saturating_mul(60)), now_millis());
+    let mut tally = BlitzTally::default();
+    while !deadline.is_expired(now_millis()) {
+        let word = choose_word(&words, &filter, &mut rng)
+            .ok_or(Error::new(ErrorKind::NotFound, "no word matches the given options"))?;
+        let mut game = new_game(cli, config, word, &words, args.lives, input.clone(), args.input.guess_time)?;
+        game.set_deadline(Some(deadline));
+        game.play();
+        if game.is_out_of_time() || game.status() == GameStatus::InProgress {
+            break;
+        }
+        let score = record_result(cli, config, &game, word);
+        tally.add(game.status() == GameStatus::Won, score.total);
+    }
+    print_blitz_summary(&tally, deadline.is_expired(now_millis()), &mut StdoutLogger);
+    Ok(if tally.solved > 0 { EXIT_SUCCESS } else { EXIT_FAILURE })
+}
+
+#[cfg(not(tarpaulin_include))]
+fn survival(cli: &Cli, config: &Config, args: &SurvivalArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { category: args.category.clone(), difficulty: None, language: Some(language) };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    let mut rng: Box<dyn RngCore> = match args.seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(thread_rng()),
+    };
+    // A script read word by word would start over with every game, so it is shared like a timed input.
+    let input = input_of(&args.input, args.input.guess_time.is_some() || args.input.guesses.is_some())?;
+    let mut run = SurvivalRun::new(args.lives.unwrap_or(config.lives)).with_ramp(args.ramp);
+    let mut last_word = String::new();
+    while !run.is_over() {
+        let word = run.choose(&words, &filter, &mut rng)
+            .ok_or(Error::new(ErrorKind::NotFound, "no word matches the given options"))?;
+        let mut game = set_up_game(cli, config, run.new_game(&word.text), input.clone(), args.input.guess_time)?;
+        if !game.is_batch() {
+            print_round(&run, &mut StdoutLogger);
+        }
+        game.play();
+        if game.status() == GameStatus::InProgress {
+            return Ok(EXIT_ERROR);
+        }
+        record_stats(&game);
+        run.finish_word(&game, ScoreRules::default().score(&ScoreInput::of(&game)).total);
+        last_word = word.text.clone();
+    }
+    print_run_summary(&run, &mut StdoutLogger);
+    let score = Score { total: run.score(), ..Score::default() };
+    record_entry(&ScoreEntry::new(now(), &player_of(cli, config), &last_word, SURVIVAL_CATEGORY, run.solved() > 0, &score));
+    Ok(if run.solved() > 0 { EXIT_SUCCESS } else { EXIT_FAILURE })
+}
+
+#[cfg(not(tarpaulin_include))]
+fn stats() -> Result<u8, Error> {
+    let records = load_records(&stats_path())?;
+    print_summary(&Summary::from_records(&records), &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn serve(cli: &Cli, config: &Config, args: &ServeArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { category: args.category.clone(), difficulty: config.difficulty, language: Some(language) };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    if !words.iter().any(|word| filter.matches(word)) {
+        return Err(Error::new(ErrorKind::NotFound, "no word matches the given options"));
+    }
+    let options = ServerOptions { words: Arc::new(words), filter, lives: args.lives.unwrap_or(config.lives), seed: args.seed, turn_timeout: Duration::from_secs(args.turn_timeout) };
+    if args.web {
+        let server = Server::http(&args.address).map_err(Error::other)?;
+        serve_web(server, options, &mut *logger_of(cli)?)?;
+        return Ok(EXIT_SUCCESS);
+    }
+    if args.http {
+        let server = Server::http(&args.address).map_err(Error::other)?;
+        serve_http(server, &mut Api::new(options), &mut *logger_of(cli)?)?;
+        return Ok(EXIT_SUCCESS);
+    }
+    let listener = TcpListener::bind(&args.address)?;
+    serve_games(listener, options, &mut *logger_of(cli)?)?;
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn solve(config: &Config, args: &SolveArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let length = Some(args.pattern.chars().count());
+    let filter = WordFilter { language: Some(language), ..WordFilter::default() };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, length)?;
+    print_solution(&words, &args.pattern, &args.misses, &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn words(config: &Config, command: &WordsCommand) -> Result<u8, Error> {
+    match command {
+        WordsCommand::Validate { path, lang, alphabet, min_length, max_length, blocklist } => {
+            let language = lang.clone().unwrap_or(config.language());
+            let mut rules = ValidationRules::for_language(&language);
+            if let Some(alphabet) = alphabet {
+                rules.alphabet = Some(alphabet.to_lowercase());
+            }
+            rules.min_length = *min_length;
+            rules.max_length = *max_length;
+            if let Some(configured) = blocklists_of(config)?.get(&language) {
+                rules.blocklist.extend(configured.clone());
+            }
+            for path in blocklist.iter() {
+                rules.blocklist.extend(load_blocklist(path)?);
+            }
+            let issues = validate_word_list(&read_to_string(path)?, &rules);
+            print_issues(&issues, &mut StdoutLogger);
+            Ok(if issues.is_empty() { EXIT_SUCCESS } else { EXIT_FAILURE })
+        }
+        WordsCommand::Build { paths, lang, output } => {
+            let language = lang.clone().unwrap_or(config.language());
+            let mut words: Vec<Word> = Vec::new();
+            for path in paths.iter() {
+                words.extend(load_word_list(path, &language)?);
+            }
+            write(output, build_index(&words))?;
+            StdoutLogger.print(format_args!("{} word(s) indexed in {}", words.len(), output.display()));
+            Ok(EXIT_SUCCESS)
+        }
+        WordsCommand::Import { path, format, aff, lang, category, min_length, max_length, min_frequency, limit, output } => {
+            let language = lang.clone().unwrap_or(config.language());
+            let content = read_to_string(path)?;
+            let entries = match format.unwrap_or(Format::of(path)) {
+                Format::Hunspell => {
+                    let affixes = aff.as_ref().map(read_to_string).transpose()?.map(|content| Affixes::parse(&content));
+                    parse_hunspell(&content, affixes.as_ref())
+                }
+                Format::Plain => parse_plain(&content),
+                Format::Frequency => parse_frequency(&content),
+            };
+            let rules = ValidationRules { min_length: *min_length, max_length: *max_length, ..ValidationRules::for_language(&language) };
+            let filter = ImportFilter { rules, min_frequency: *min_frequency, limit: *limit };
+            let blocklists = blocklists_of(config)?;
+            let words = import_words(entries, &filter, blocklists.get(&language).unwrap_or(&Blocklist::default()));
+            let list = format_word_list(&words, category);
+            match output {
+                Some(output) => {
+                    write(output, list)?;
+                    StdoutLogger.print(format_args!("{} word(s) written to {}", words.len(), output.display()));
+                }
+                None => stdout().write_all(list.as_bytes())?,
+            }
+            Ok(if words.is_empty() { EXIT_FAILURE } else { EXIT_SUCCESS })
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn replay(args: &ReplayArgs) -> Result<u8, Error> {
+    let invalid = |message: String| Error::new(ErrorKind::InvalidData, format!("{}: {message}", args.path.display()));
+    let games = split_games(parse_event_log(&read_to_string(&args.path)?).map_err(invalid)?);
+    let index = args.game.map_or(games.len(), |game| game as usize);
+    let records = games.get(index.wrapping_sub(1))
+        .ok_or(invalid(format!("there is no game {index}, the log has {} game(s)", games.len())))?;
+    let (_, steps) = build_steps(records).map_err(invalid)?;
+    run_replay(&steps, ReplayControls::new(args.paused, args.speed), &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn leaderboard(args: &LeaderboardArgs) -> Result<u8, Error> {
+    let view = match (&args.category, args.weekly) {
+        (Some(category), _) => View::Category(category.clone()),
+        (None, true) => View::Weekly,
+        (None, false) => View::AllTime,
+    };
+    let entries = load_scores(&scores_path())?;
+    print_leaderboard(&standings(&entries, &view, now()), &view, args.limit as usize, &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn profile(cli: &Cli, config: &Config, args: &ProfileArgs) -> Result<u8, Error> {
+    let name = args.name.clone().unwrap_or_else(|| player_of(cli, config));
+    let profiles = load_profiles(&profiles_path())?;
+    let profile = profiles.iter().find(|profile| same_player(&profile.name, &name))
+        .ok_or(Error::new(ErrorKind::NotFound, format!("no profile for {name}, play a game first")))?;
+    print_profile(profile, &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn trophies(cli: &Cli, config: &Config, args: &ProfileArgs) -> Result<u8, Error> {
+    let name = args.name.clone().unwrap_or_else(|| player_of(cli, config));
+    print_trophies(&clean_name(&name), &load_unlocks(&achievements_path())?, &mut StdoutLogger);
+    Ok(EXIT_SUCCESS)
+}
+
+#[cfg(not(tarpaulin_include))]
+fn bot(config: &Config, args: &BotArgs) -> Result<u8, Error> {
+    let language = args.lang.clone().unwrap_or(config.language());
+    let filter = WordFilter { category: args.category.clone(), difficulty: config.difficulty, language: Some(language) };
+    let words = load_words_matching(&word_lists_of(&args.word_list, config), &filter, &blocklists_of(config)?, None)?;
+    let mut bot = Bot::new(words, filter, args.lives.unwrap_or(config.lives), args.seed).with_prefix(&args.prefix);
+    bot.run(&mut LineTransport::new(stdin().lock(), stdout()))?;
+    Ok(EXIT_SUCCESS)
+}
@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use toml::{Table, Value};
+
+use crate::constants::attempts::SIX;
+use crate::random_word::word_list::{Difficulty, DEFAULT_LANGUAGE};
+
+pub const CONFIG_FILE: &str = "config.toml";
+pub const PROJECT_CONFIG_FILE: &str = "jogo_da_forca.toml";
+pub const ENV_PREFIX: &str = "JOGO_DA_FORCA_";
+const UNKNOWN_KEY: &str = "unknown key";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Classic,
+    Compact,
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "classic" => Ok(Theme::Classic),
+            "compact" => Ok(Theme::Compact),
+            _ => Err(format!("unknown theme `{value}` (expected classic or compact)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    pub quit: String,
+    pub hint: String,
+    pub undo: String,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings { quit: ":q".to_string(), hint: ":h".to_string(), undo: ":u".to_string() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub difficulty: Option<Difficulty>,
+    pub lives: u8,
+    pub word_lists: Vec<PathBuf>,
+    pub locale: String,
+    pub theme: Theme,
+    pub sound: bool,
+    pub animations: bool,
+    pub accessible: bool,
+    pub keys: KeyBindings,
+    pub player: Option<String>,
+    /// Leave out the words of the built-in blocklists.
+    pub family_friendly: bool,
+    /// Extra blocklist files by language.
+    pub blocklists: BTreeMap<String, Vec<PathBuf>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            difficulty: None,
+            lives: SIX,
+            word_lists: Vec::new(),
+            locale: DEFAULT_LANGUAGE.to_string(),
+            theme: Theme::Classic,
+            sound: false,
+            animations: true,
+            accessible: false,
+            keys: KeyBindings::default(),
+            player: None,
+            family_friendly: true,
+            blocklists: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub source: String,
+    pub key: String,
+    pub message: String,
+}
+
+impl ConfigError {
+    pub fn new(source: &str, key: &str, message: String) -> ConfigError {
+        ConfigError { source: source.to_string(), key: key.to_string(), message }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}: {}", self.source, self.message)
+        } else {
+            write!(f, "{}: `{}`: {}", self.source, self.key, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn expect_str<'a>(value: &'a Value, expected: &str) -> Result<&'a str, String> {
+    value.as_str().ok_or(format!("expected {expected}, found {}", value.type_str()))
+}
+
+fn expect_bool(value: &Value) -> Result<bool, String> {
+    value.as_bool().ok_or(format!("expected true or false, found {}", value.type_str()))
+}
+
+fn expect_binding(value: &Value) -> Result<String, String> {
+    let binding = expect_str(value, "a string")?.trim();
+    if binding.chars().count() < 2 {
+        return Err(format!("`{binding}` is too short, bindings need at least two characters so they are not taken as a guess"));
+    }
+    Ok(binding.to_string())
+}
+
+fn expect_locale(value: &Value) -> Result<String, String> {
+    let locale = expect_str(value, "a string")?;
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+    let valid_language = (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic());
+    let valid_region = region.is_none_or(|region| region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()));
+    if !valid_language || !valid_region || parts.next().is_some() {
+        return Err(format!("`{locale}` is not a locale such as `en` or `pt-BR`"));
+    }
+    Ok(locale.to_string())
+}
+
+fn expect_paths(value: &Value, base: &Path) -> Result<Vec<PathBuf>, String> {
+    let paths: Vec<&Value> = match value {
+        Value::Array(paths) => paths.iter().collect(),
+        _ => vec![value],
+    };
+    paths.into_iter()
+        .map(|path| expect_str(path, "a path or a list of paths").map(|path| base.join(path)))
+        .collect()
+}
+
+fn expect_language(language: &str) -> Result<String, String> {
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("`{language}` is not a language such as `en` or `pt`"));
+    }
+    Ok(language.to_lowercase())
+}
+
+fn expect_player(value: &Value) -> Result<String, String> {
+    let player = expect_str(value, "a string")?.trim();
+    if player.is_empty() || player.contains(',') {
+        return Err(format!("`{player}` is not a player name, it must not be empty or contain commas"));
+    }
+    Ok(player.to_string())
+}
+
+fn env_value(key: &str, raw: &str) -> Value {
+    match key {
+        "lives" => raw.trim().parse::<i64>().map(Value::Integer).unwrap_or(Value::String(raw.to_string())),
+        "sound" | "animations" | "accessible" | "family_friendly" => raw.trim().parse::<bool>().map(Value::Boolean).unwrap_or(Value::String(raw.to_string())),
+        _ if key == "word_lists" || key.starts_with("blocklists.") => Value::Array(raw.split(',').map(|path| Value::String(path.trim().to_string())).filter(|path| path.as_str() != Some("")).collect()),
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+pub fn config_dir() -> PathBuf {
+    if let Some(config_home) = env::var_os("XDG_CONFIG_HOME").filter(|value| !value.is_empty()) {
+        return PathBuf::from(config_home).join("jogo_da_forca");
+    }
+    if let Some(app_data) = env::var_os("APPDATA").filter(|value| !value.is_empty()) {
+        return PathBuf::from(app_data).join("jogo_da_forca");
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")).unwrap_or_default();
+    PathBuf::from(home).join(".config").join("jogo_da_forca")
+}
+
+pub fn user_config_path() -> PathBuf {
+    config_dir().join(CONFIG_FILE)
+}
+
+impl Config {
+    pub fn language(&self) -> String {
+        self.locale.split(['-', '_']).next().unwrap_or(DEFAULT_LANGUAGE).to_lowercase()
+    }
+
+    /// The configured player, else the login name, so scores land on the same profile by default.
+    pub fn player_name(&self) -> String {
+        self.player.clone()
+            .or_else(|| env::var("USER").ok())
+            .or_else(|| env::var("USERNAME").ok())
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or("player".to_string())
+    }
+
+    pub fn set(&mut self, key: &str, value: &Value, base: &Path) -> Result<(), String> {
+        match key {
+            "difficulty" => self.difficulty = Some(expect_str(value, "easy, medium or hard")?.parse()?),
+            "lives" => {
+                let lives = value.as_integer().filter(|lives| (1..=u8::MAX as i64).contains(lives));
+                self.lives = lives.ok_or(format!("expected an integer between 1 and {}", u8::MAX))? as u8;
+            }
+            "word_lists" => self.word_lists = expect_paths(value, base)?,
+            "locale" => self.locale = expect_locale(value)?,
+            "theme" => self.theme = expect_str(value, "classic or compact")?.parse()?,
+            "sound" => self.sound = expect_bool(value)?,
+            "animations" => self.animations = expect_bool(value)?,
+            "accessible" => self.accessible = expect_bool(value)?,
+            "keys.quit" => self.keys.quit = expect_binding(value)?,
+            "keys.hint" => self.keys.hint = expect_binding(value)?,
+            "keys.undo" => self.keys.undo = expect_binding(value)?,
+            "player" => self.player = Some(expect_player(value)?),
+            "family_friendly" => self.family_friendly = expect_bool(value)?,
+            _ => match key.strip_prefix("blocklists.") {
+                Some(language) => {
+                    let language = expect_language(language)?;
+                    self.blocklists.insert(language, expect_paths(value, base)?);
+                }
+                None => return Err(UNKNOWN_KEY.to_string()),
+            },
+        }
+        Ok(())
+    }
+
+    pub fn apply_table(&mut self, table: &Table, source: &str, base: &Path) -> Result<(), ConfigError> {
+        for (key, value) in table.iter() {
+            match (key.as_str(), value) {
+                ("keys" | "blocklists", Value::Table(entries)) => {
+                    for (name, value) in entries.iter() {
+                        let key = format!("{key}.{name}");
+                        self.set(&key, value, base).map_err(|message| ConfigError::new(source, &key, message))?;
+                    }
+                }
+                _ => self.set(key, value, base).map_err(|message| ConfigError::new(source, key, message))?,
+            }
+        }
+        self.validate(source)
+    }
+
+    pub fn apply_str(&mut self, content: &str, source: &str, base: &Path) -> Result<(), ConfigError> {
+        let table: Table = content.parse().map_err(|error: toml::de::Error| ConfigError::new(source, "", error.message().to_string()))?;
+        self.apply_table(&table, source, base)
+    }
+
+    pub fn apply_file(&mut self, path: &Path) -> Result<bool, ConfigError> {
+        let source = path.display().to_string();
+        match read_to_string(path) {
+            Ok(content) => {
+                let base = path.parent().unwrap_or(Path::new("."));
+                self.apply_str(&content, &source, base).map(|_| true)
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(ConfigError::new(&source, "", error.to_string())),
+        }
+    }
+
+    /// Applies the `JOGO_DA_FORCA_*` variables, returning the names of the ones that are not a setting.
+    ///
+    /// Unlike a config file, the environment is shared with other programs and versions, so an unknown variable is
+    /// left for the caller to warn about instead of stopping the game.
+    pub fn apply_env<I>(&mut self, vars: I, base: &Path) -> Result<Vec<String>, ConfigError>
+        where
+            I: IntoIterator<Item = (String, String)>,
+    {
+        let mut unknown: Vec<String> = Vec::new();
+        for (name, raw) in vars.into_iter() {
+            let Some(key) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let key = key.to_lowercase().replacen("keys_", "keys.", 1).replacen("blocklists_", "blocklists.", 1);
+            let source = format!("environment variable {name}");
+            match self.set(&key, &env_value(&key, &raw), base) {
+                Err(message) if message == UNKNOWN_KEY => unknown.push(name),
+                result => result.map_err(|message| ConfigError::new(&source, "", message))?,
+            }
+            self.validate(&source)?;
+        }
+        Ok(unknown)
+    }
+
+    pub fn validate(&self, source: &str) -> Result<(), ConfigError> {
+        if self.keys.quit == self.keys.hint {
+            return Err(ConfigError::new(source, "keys.hint", format!("`{}` is already bound to keys.quit", self.keys.hint)));
+        }
+        for (key, binding) in [("keys.quit", &self.keys.quit), ("keys.hint", &self.keys.hint)] {
+            if *binding == self.keys.undo {
+                return Err(ConfigError::new(source, "keys.undo", format!("`{binding}` is already bound to {key}")));
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn load(explicit: Option<&Path>) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        config.apply_file(&user_config_path())?;
+        let current_dir = env::current_dir().unwrap_or_default();
+        config.apply_file(&current_dir.join(PROJECT_CONFIG_FILE))?;
+        if let Some(path) = explicit {
+            if !config.apply_file(path)? {
+                return Err(ConfigError::new(&path.display().to_string(), "", "file not found".to_string()));
+            }
+        }
+        for name in config.apply_env(env::vars(), &current_dir)? {
+            eprintln!("warning: ignoring environment variable {name}: unknown key");
+        }
+        Ok(config)
+    }
+}
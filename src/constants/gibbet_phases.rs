@@ -1,4 +1,4 @@
-pub const GIBBET: &'static str = r#"
+pub const GIBBET: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -13,7 +13,7 @@ pub const GIBBET: &'static str = r#"
     |
 "#;
 
-pub const HEAD: &'static str = r#"
+pub const HEAD: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -28,7 +28,7 @@ pub const HEAD: &'static str = r#"
     |
 "#;
 
-pub const HEAD_AND_RIGHT_ARM: &'static str = r#"
+pub const HEAD_AND_RIGHT_ARM: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -43,7 +43,7 @@ pub const HEAD_AND_RIGHT_ARM: &'static str = r#"
     |
 "#;
 
-pub const HEAD_RIGHT_ARM_AND_LEFT_ARM: &'static str = r#"
+pub const HEAD_RIGHT_ARM_AND_LEFT_ARM: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -58,7 +58,7 @@ pub const HEAD_RIGHT_ARM_AND_LEFT_ARM: &'static str = r#"
     |
 "#;
 
-pub const HEAD_RIGHT_ARM_LEFT_ARM_AND_STEM: &'static str = r#"
+pub const HEAD_RIGHT_ARM_LEFT_ARM_AND_STEM: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -72,7 +72,7 @@ pub const HEAD_RIGHT_ARM_LEFT_ARM_AND_STEM: &'static str = r#"
     |
 "#;
 
-pub const HEAD_RIGHT_ARM_LEFT_ARM_STEM_AND_RIGHT_LEG: &'static str = r#"
+pub const HEAD_RIGHT_ARM_LEFT_ARM_STEM_AND_RIGHT_LEG: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -86,7 +86,7 @@ pub const HEAD_RIGHT_ARM_LEFT_ARM_STEM_AND_RIGHT_LEG: &'static str = r#"
     |
 "#;
 
-pub const HEAD_RIGHT_ARM_LEFT_ARM_STEM_RIGHT_LEG_AND_LEFT_LEG: &'static str = r#"
+pub const HEAD_RIGHT_ARM_LEFT_ARM_STEM_RIGHT_LEG_AND_LEFT_LEG: &str = r#"
     |________________
     |  /            |
     | /             |
@@ -1,125 +1,624 @@
-use std::fmt::Debug;
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::Duration;
 
-use crate::logger::logger::{StdoutLogger};
-use crate::{clear_screen, end_of_game, get_answer, get_blank_chars, get_occurrences, lost, print_gibbet_status, print_sequence, win, word_contains_letter};
+use serde::{Deserialize, Serialize};
+
+use crate::achievements::achievements::{Achievement, AchievementTracker};
+use crate::accessibility::accessibility::{announce_hit, announce_miss, print_accessible_game};
+use crate::animation::animation::{celebrate, draw_limb, swing, FrameScheduler};
+use crate::batch::batch::{print_achievement, print_guess, print_hint, print_result, print_state, print_timeout, print_undo, Input};
+use crate::logger::event::{now_millis, EventLog, EventRecord, GameEvent};
+use crate::logger::logger::{Level, Logger, StdoutLogger};
+use crate::logger::sinks::MultiLogger;
+use crate::config::config::{KeyBindings, Theme};
+use crate::constants::attempts::SIX;
+use crate::timer::timer::{format_time_left, Countdown, Poll, TICK};
+use crate::{clear_screen, end_of_game, get_blank_chars, get_occurrences, gibbet_stage, lost_with_lives, print_compact_gibbet, print_gibbet_status, print_sequence, print_word_hits, ring_bell, win, word_contains_letter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub formed_word_by_hits: Vec<String>,
+    pub hits: Vec<String>,
+    pub errors: Vec<String>,
+    pub attempts: u8,
+    pub lives: u8,
+    pub hints_used: u8,
+}
+
+impl Snapshot {
+    pub fn stage(&self) -> u8 {
+        gibbet_stage(self.attempts, self.lives)
+    }
+}
 
-#[derive(Debug, Clone)]
 pub struct HangmanGame {
-    word: &'static str,
+    word: String,
     formed_word_by_hits: Vec<String>,
     hits: Vec<String>,
     errors: Vec<String>,
     attempts: u8,
+    lives: u8,
+    scheduler: FrameScheduler,
+    accessible: bool,
+    theme: Theme,
+    sound: bool,
+    keys: KeyBindings,
+    hints_used: u8,
+    quit: bool,
+    input: Input,
+    batch: bool,
+    input_closed: bool,
+    events: EventLog,
+    finished: bool,
+    logger: Box<dyn Logger>,
+    guess_time: Option<Duration>,
+    /// The clock of the guess being typed, kept across empty and rejected lines until a guess is made.
+    guess_clock: Option<Countdown>,
+    deadline: Option<Countdown>,
+    out_of_time: bool,
+    achievements: Option<AchievementTracker>,
+    practice: bool,
+    history: Vec<Snapshot>,
+    undos: u32,
+}
+
+impl fmt::Debug for HangmanGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HangmanGame")
+            .field("word", &self.word)
+            .field("formed_word_by_hits", &self.formed_word_by_hits)
+            .field("hits", &self.hits)
+            .field("errors", &self.errors)
+            .field("attempts", &self.attempts)
+            .field("lives", &self.lives)
+            .field("hints_used", &self.hints_used)
+            .field("guess_time", &self.guess_time)
+            .field("deadline", &self.deadline)
+            .field("achievements", &self.achievements)
+            .field("practice", &self.practice)
+            .field("undos", &self.undos)
+            .field("events", &self.events)
+            .finish_non_exhaustive()
+    }
 }
 
 pub trait Game {
-    fn new(word: &'static str, formed_word_by_hits: Vec<String>, hits: Vec<String>, errors: Vec<String>, attempts: u8) -> Self;
-    fn play(&mut self) -> ();
+    fn new(word: &str, formed_word_by_hits: Vec<String>, hits: Vec<String>, errors: Vec<String>, attempts: u8) -> Self;
+    fn play(&mut self);
+
+    fn add_letter_to_hits(&mut self, letter: String);
+
+    fn add_letter_to_empty_word(&mut self, letter: String);
+
+    fn add_letter_to_errors(&mut self, letter: String);
+
+    fn print_hits(&mut self);
+
+    fn print_errors(&mut self);
+
+    fn correct_answer(&mut self, input: String);
+
+    fn wrong_answer(&mut self, input: String);
+
+    fn print_game(&mut self);
+
+    fn verify_answer(&mut self, input: String);
+
+    fn prompt_user(&mut self);
+
+    fn run(&mut self);
+}
+
+impl HangmanGame {
+    /// A started game that never touches the terminal, for players on the other end of a connection or a binding.
+    pub fn headless(word: &str, lives: u8) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_lives(lives);
+        game.set_batch(Input::Lines(VecDeque::new()));
+        game.set_logger(Box::new(MultiLogger::new()));
+        game.start();
+        game
+    }
+
+    pub fn set_scheduler(&mut self, scheduler: FrameScheduler) {
+        self.scheduler = scheduler;
+    }
+
+    pub fn scheduler(&self) -> FrameScheduler {
+        self.scheduler
+    }
+
+    pub fn set_accessible(&mut self, accessible: bool) {
+        self.accessible = accessible;
+        if accessible {
+            self.scheduler = FrameScheduler::disabled();
+        }
+    }
+
+    pub fn is_accessible(&self) -> bool {
+        self.accessible
+    }
+
+    pub fn set_batch(&mut self, input: Input) {
+        self.batch = !input.is_interactive();
+        self.input = input;
+        if self.batch {
+            self.scheduler = FrameScheduler::disabled();
+        }
+    }
+
+    pub fn is_batch(&self) -> bool {
+        self.batch
+    }
+
+    pub fn set_lives(&mut self, lives: u8) {
+        self.lives = lives.max(1);
+    }
+
+    pub fn lives(&self) -> u8 {
+        self.lives
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_sound(&mut self, sound: bool) {
+        self.sound = sound;
+    }
+
+    pub fn set_key_bindings(&mut self, keys: KeyBindings) {
+        self.keys = keys;
+    }
+
+    pub fn hints_used(&self) -> u8 {
+        self.hints_used
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn attempts(&self) -> u8 {
+        self.attempts
+    }
+
+    pub fn stage(&self) -> u8 {
+        gibbet_stage(self.attempts, self.lives)
+    }
+
+    pub fn status(&self) -> GameStatus {
+        if !self.formed_word_by_hits.is_empty() && win(&self.word, &self.formed_word_by_hits) {
+            GameStatus::Won
+        } else if self.quit || lost_with_lives(self.attempts, self.lives) {
+            GameStatus::Lost
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    /// Every guess must come within `limit`, waiting longer costs a life.
+    pub fn set_guess_time(&mut self, limit: Option<Duration>) {
+        self.guess_time = limit;
+    }
+
+    pub fn guess_time(&self) -> Option<Duration> {
+        self.guess_time
+    }
+
+    /// The game is lost once the deadline passes, as in the last word of a blitz.
+    pub fn set_deadline(&mut self, deadline: Option<Countdown>) {
+        self.deadline = deadline;
+    }
+
+    pub fn is_out_of_time(&self) -> bool {
+        self.out_of_time
+    }
+
+    /// Practice games can take back guesses, they do not count as ranked games.
+    pub fn set_practice(&mut self, practice: bool) {
+        self.practice = practice;
+    }
+
+    pub fn is_practice(&self) -> bool {
+        self.practice
+    }
+
+    pub fn undos(&self) -> u32 {
+        self.undos
+    }
+
+    /// Called before every guess, hint and timeout: keeps the state for undo and gives the next guess a new clock.
+    fn remember(&mut self) {
+        self.guess_clock = None;
+        if self.practice {
+            self.history.push(self.snapshot());
+        }
+    }
+
+    /// Rolls back the last guess, hint or timeout of a practice game, hints already used stay used.
+    pub fn undo(&mut self) -> bool {
+        if !self.practice || self.finished {
+            return false;
+        }
+        let Some(snapshot) = self.history.pop() else {
+            return false;
+        };
+        self.formed_word_by_hits = snapshot.formed_word_by_hits;
+        self.hits = snapshot.hits;
+        self.errors = snapshot.errors;
+        self.attempts = snapshot.attempts;
+        self.undos = self.undos.saturating_add(1);
+        self.logger.log(Level::Debug, format_args!("guess undone attempts={}/{}, {} undo(s)", self.attempts, self.lives, self.undos));
+        self.events.emit(GameEvent::GuessUndone { attempts: self.attempts });
+        true
+    }
+
+    /// Checks the achievements of the player when the game is over, practice games unlock none.
+    pub fn set_achievements(&mut self, tracker: AchievementTracker) {
+        self.achievements = Some(tracker);
+    }
+
+    /// The achievements this game unlocked for the first time.
+    pub fn unlocked_achievements(&self) -> Vec<Achievement> {
+        self.achievements.as_ref().map(|tracker| tracker.newly_unlocked().to_vec()).unwrap_or_default()
+    }
 
-    fn add_letter_to_hits(&mut self, letter: String) -> ();
+    pub fn set_logger(&mut self, logger: Box<dyn Logger>) {
+        self.logger = logger;
+    }
+
+    pub fn add_event_sink(&mut self, sink: Box<dyn Logger>) {
+        self.events.add_sink(sink);
+    }
+
+    pub fn events(&self) -> &[EventRecord] {
+        self.events.records()
+    }
 
-    fn add_letter_to_empty_word(&mut self, letter: String) -> ();
+    /// Milliseconds between the start of the game and its last event.
+    pub fn elapsed_millis(&self) -> u64 {
+        match (self.events.records().first(), self.events.records().last()) {
+            (Some(first), Some(last)) => last.timestamp.saturating_sub(first.timestamp),
+            _ => 0,
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            formed_word_by_hits: self.formed_word_by_hits.clone(),
+            hits: self.hits.clone(),
+            errors: self.errors.clone(),
+            attempts: self.attempts,
+            lives: self.lives,
+            hints_used: self.hints_used,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.formed_word_by_hits = get_blank_chars(self.word.chars().count());
+        self.events.emit(GameEvent::GameStarted { word: self.word.clone(), lives: self.lives, attempts: self.attempts });
+        self.logger.log(Level::Debug, format_args!("game started: word={} lives={}", self.word, self.lives));
+    }
+
+    pub fn use_hint(&mut self) -> Option<String> {
+        let word: Vec<char> = self.word.to_lowercase().chars().collect();
+        let index = self.formed_word_by_hits.iter().position(|letter| letter.trim().is_empty())?;
+        let letter = word.get(index)?.to_string();
+        self.remember();
+        self.hints_used += 1;
+        self.correct_answer(letter.clone());
+        let positions: Vec<usize> = get_occurrences(letter.clone(), &self.word);
+        self.logger.log(Level::Debug, format_args!("hint {} revealed at {:?}, {} hint(s) used", letter, positions, self.hints_used));
+        self.events.emit(GameEvent::HintUsed { letter: letter.clone(), positions });
+        self.check_finished();
+        Some(letter)
+    }
+
+    pub fn guess_word(&mut self, input: String) -> bool {
+        self.remember();
+        let correct = input.to_lowercase() == self.word.to_lowercase();
+        if correct {
+            self.formed_word_by_hits = self.word.to_lowercase().chars().map(|letter| letter.to_string()).collect();
+        } else {
+            self.wrong_answer(input.to_lowercase());
+        }
+        self.logger.log(Level::Debug, format_args!("word guess {} correct={} attempts={}/{}", input.to_lowercase(), correct, self.attempts, self.lives));
+        self.events.emit(GameEvent::WordGuessed { word: input.to_lowercase(), correct });
+        self.check_finished();
+        correct
+    }
 
-    fn add_letter_to_errors(&mut self, letter: String) -> ();
+    pub fn quit(&mut self) {
+        self.quit = true;
+        self.check_finished();
+    }
 
-    fn print_hits(&mut self) -> ();
+    /// The player took too long to guess and loses a life, as if they had missed.
+    pub fn time_out(&mut self) {
+        self.remember();
+        self.attempts += 1;
+        self.logger.log(Level::Debug, format_args!("guess timed out attempts={}/{}", self.attempts, self.lives));
+        self.events.emit(GameEvent::GuessTimedOut { attempts: self.attempts });
+        self.check_finished();
+    }
 
-    fn print_errors(&mut self) -> ();
+    fn announce_timeout(&mut self) {
+        if self.batch {
+            print_timeout(&mut *self.logger);
+            return;
+        }
+        if self.sound {
+            ring_bell(&mut *self.logger);
+        }
+        self.logger.print(format_args!("Time is up, you lost a life."));
+    }
 
-    fn correct_answer(&mut self, input: String) -> ();
+    /// Ends the game as lost when the deadline set with `set_deadline` passes.
+    pub fn run_out_of_time(&mut self) {
+        self.out_of_time = true;
+        self.logger.log(Level::Debug, format_args!("deadline passed"));
+        self.quit();
+    }
 
-    fn wrong_answer(&mut self, input: String) -> ();
+    fn is_timed(&self) -> bool {
+        self.guess_time.is_some() || self.deadline.is_some()
+    }
 
-    fn print_game(&mut self) -> ();
+    /// Prints the clock under the game, then only rewrites that line as it ticks so the letters typed stay in view.
+    fn print_clock(&mut self, clock: &str, first: bool) {
+        if self.batch {
+            return;
+        }
+        let prompted = self.input.is_timed();
+        if !first {
+            self.logger.reprint(if prompted { 2 } else { 1 }, format_args!("{clock}"));
+            return;
+        }
+        self.logger.print(format_args!("{clock}"));
+        if prompted {
+            self.logger.print(format_args!("Choose a letter: "));
+        }
+    }
 
-    fn verify_answer(&mut self, input: String) -> ();
+    /// Waits for the next guess while the clocks run, redrawing them as they tick.
+    fn read_guess(&mut self) -> Poll {
+        if !self.is_timed() {
+            return match self.input.next_line() {
+                Some(line) => Poll::Line(line),
+                None => Poll::Closed,
+            };
+        }
+        if self.guess_clock.is_none() {
+            self.guess_clock = self.guess_time.map(|limit| Countdown::new(limit, now_millis()));
+        }
+        let countdown = self.guess_clock;
+        let mut shown = String::new();
+        loop {
+            let now = now_millis();
+            if self.deadline.is_some_and(|deadline| deadline.is_expired(now)) {
+                self.run_out_of_time();
+                return Poll::Pending;
+            }
+            if countdown.is_some_and(|countdown| countdown.is_expired(now)) {
+                self.time_out();
+                self.announce_timeout();
+                return Poll::Pending;
+            }
+            let clock = format_time_left(countdown.map(|countdown| countdown.remaining(now)), self.deadline.map(|deadline| deadline.remaining(now)));
+            // Screen readers would repeat the whole clock every second, so it is only announced once per guess.
+            if clock != shown && (!self.accessible || shown.is_empty()) {
+                self.print_clock(&clock, shown.is_empty());
+                shown = clock;
+            }
+            match self.input.poll_line(TICK) {
+                Poll::Pending => (),
+                polled => return polled,
+            }
+        }
+    }
 
-    fn prompt_user(&mut self) -> ();
+    fn check_finished(&mut self) {
+        if self.finished {
+            return;
+        }
+        let event = match self.status() {
+            GameStatus::Won => GameEvent::GameWon { attempts: self.attempts, hints: self.hints_used },
+            GameStatus::Lost => GameEvent::GameLost { attempts: self.attempts, hints: self.hints_used },
+            GameStatus::InProgress => return,
+        };
+        self.finished = true;
+        self.logger.log(Level::Debug, format_args!("game over: {:?} after {} attempt(s), {} hint(s)", self.status(), self.attempts, self.hints_used));
+        self.events.emit(event);
+        if let Some(mut tracker) = self.achievements.take().filter(|_| !self.practice) {
+            let unlocked = tracker.check(self);
+            self.logger.log(Level::Debug, format_args!("achievements unlocked: {:?}", unlocked));
+            self.achievements = Some(tracker);
+        }
+    }
 
-    fn run(&mut self) -> ();
+    fn clear_screen(&self) {
+        if !self.accessible && !self.batch {
+            clear_screen();
+        }
+    }
 }
 
 impl Game for HangmanGame {
-    fn new(word: &'static str, formed_word_by_hits: Vec<String>, hits: Vec<String>, errors: Vec<String>, attempts: u8) -> HangmanGame {
-        HangmanGame {word, formed_word_by_hits, hits, errors, attempts}
+    fn new(word: &str, formed_word_by_hits: Vec<String>, hits: Vec<String>, errors: Vec<String>, attempts: u8) -> HangmanGame {
+        HangmanGame {word: word.to_string(), formed_word_by_hits, hits, errors, attempts, lives: SIX, scheduler: FrameScheduler::disabled(), accessible: false, theme: Theme::Classic, sound: false, keys: KeyBindings::default(), hints_used: 0, quit: false, input: Input::default(), batch: false, input_closed: false, events: EventLog::default(), finished: false, logger: Box::new(StdoutLogger), guess_time: None, guess_clock: None, deadline: None, out_of_time: false, achievements: None, practice: false, history: Vec::new(), undos: 0}
     }
 
-    fn play(&mut self) -> () {
-        clear_screen();
-        self.formed_word_by_hits = get_blank_chars(self.word.len());
+    fn play(&mut self) {
+        self.clear_screen();
+        self.start();
         self.run();
     }
 
-    fn add_letter_to_hits(&mut self, letter: String) -> () {
+    fn add_letter_to_hits(&mut self, letter: String) {
         self.hits.push(letter);
     }
 
-    fn add_letter_to_empty_word(&mut self, letter: String) -> () {
-        let occurrences: Vec<usize> = get_occurrences(letter.clone(), self.word);
+    fn add_letter_to_empty_word(&mut self, letter: String) {
+        let occurrences: Vec<usize> = get_occurrences(letter.clone(), &self.word);
         for index in occurrences.iter() {
             self.formed_word_by_hits[*index] = letter.clone();
         }
     }
 
-    fn add_letter_to_errors(&mut self, letter: String) -> () {
+    fn add_letter_to_errors(&mut self, letter: String) {
         self.errors.push(letter);
     }
 
-    fn print_hits(&mut self) -> () {
-        let blank_chars: Vec<String> = get_blank_chars(self.word.len());
-        let _self = &mut *self.formed_word_by_hits;
-        let mut std_out_logger = StdoutLogger;
-        print_sequence(" ".to_string(), _self.to_vec(), &mut std_out_logger);
-        print_sequence("_".to_string(), blank_chars, &mut std_out_logger);
+    fn print_hits(&mut self) {
+        print_word_hits(&self.formed_word_by_hits, &mut *self.logger);
     }
 
-    fn print_errors(&mut self) -> () {
-        let _self = &mut *self.errors;
-        let mut std_out_logger = StdoutLogger;
-        print_sequence(" ".to_string(), _self.to_vec(), &mut std_out_logger);
+    fn print_errors(&mut self) {
+        print_sequence(" ".to_string(), self.errors.to_vec(), &mut *self.logger);
     }
 
-    fn correct_answer(&mut self, input: String) -> () {
+    fn correct_answer(&mut self, input: String) {
         self.add_letter_to_hits(input.clone());
         self.add_letter_to_empty_word(input.clone());
     }
 
-    fn wrong_answer(&mut self, input: String) -> () {
+    fn wrong_answer(&mut self, input: String) {
         self.attempts += 1;
         self.add_letter_to_errors(input);
     }
 
-    fn print_game(&mut self) -> () {
-        let mut std_out_logger = StdoutLogger;
-        println!("\n\t\tJogo da Forca");
+    fn print_game(&mut self) {
+        if self.batch {
+            print_state(&self.formed_word_by_hits, &self.errors, self.attempts, self.lives, &mut *self.logger);
+            return;
+        }
+        if self.accessible {
+            print_accessible_game(&self.formed_word_by_hits, &self.errors, self.stage(), &mut *self.logger);
+            return;
+        }
+        self.logger.print(format_args!("\n\t\tJogo da Forca"));
         self.print_errors();
-        println!("\n");
-        print_gibbet_status(self.attempts, &mut std_out_logger);
-        println!("\n");
+        self.logger.print(format_args!("\n"));
+        match self.theme {
+            Theme::Classic => print_gibbet_status(self.stage(), &mut *self.logger),
+            Theme::Compact => print_compact_gibbet(self.attempts, self.lives, &mut *self.logger),
+        }
+        self.logger.print(format_args!("\n"));
         self.print_hits();
-        println!("\n");
+        self.logger.print(format_args!("\n"));
     }
 
-    fn verify_answer(&mut self, input: String) -> () {
-        if word_contains_letter(input.to_lowercase().to_string(), self.word.to_lowercase()) {
+    fn verify_answer(&mut self, input: String) {
+        if input.chars().count() > 1 {
+            let correct = self.guess_word(input.clone());
+            if self.batch {
+                print_guess(&input, correct, &mut *self.logger);
+            } else if !correct && self.accessible {
+                self.logger.print(format_args!("Wrong. The word is not {}.", input.to_uppercase()));
+            }
+            return;
+        }
+        self.remember();
+        let positions: Vec<usize> = get_occurrences(input.clone(), &self.word);
+        let hit = word_contains_letter(input.to_lowercase().to_string(), self.word.to_lowercase());
+        self.logger.log(Level::Debug, format_args!("letter guess {} hit={} positions={:?}", input.to_lowercase(), hit, positions));
+        self.events.emit(GameEvent::LetterGuessed { letter: input.to_lowercase(), hit, positions: positions.clone() });
+        if hit {
             self.correct_answer(input.to_lowercase().to_string());
+            if self.batch {
+                print_guess(&input, true, &mut *self.logger);
+            } else if self.accessible {
+                self.logger.print(format_args!("{}", announce_hit(&input, &positions)));
+            }
         } else {
             self.wrong_answer(input.to_lowercase().to_string());
+            if self.sound && !self.batch {
+                ring_bell(&mut *self.logger);
+            }
+            if self.batch {
+                print_guess(&input, false, &mut *self.logger);
+            } else if self.accessible {
+                self.logger.print(format_args!("{}", announce_miss(&input, self.attempts, self.lives)));
+            }
         }
+        self.check_finished();
     }
 
-    fn prompt_user(&mut self) -> () {
-        let mut input = get_answer();
-        input.pop();
-        self.verify_answer(input);
+    fn prompt_user(&mut self) {
+        let input = match self.read_guess() {
+            Poll::Line(input) => input,
+            Poll::Pending => return,
+            Poll::Closed => {
+                self.logger.log(Level::Debug, format_args!("input closed"));
+                self.input_closed = true;
+                return;
+            }
+        };
+        let input = input.trim().to_string();
+        if input.is_empty() {
+            return;
+        }
+        if input == self.keys.quit {
+            self.quit();
+        } else if input == self.keys.undo {
+            let undone = self.undo();
+            if self.batch {
+                print_undo(undone, &mut *self.logger);
+            } else if !undone && !self.practice {
+                self.logger.print(format_args!("Undo is only available in practice mode"));
+            } else if !undone {
+                self.logger.print(format_args!("Nothing to undo"));
+            }
+        } else if input == self.keys.hint {
+            match self.use_hint() {
+                Some(letter) if self.batch => print_hint(&letter, &mut *self.logger),
+                Some(letter) => self.logger.print(format_args!("Hint: the word contains the letter {}", letter.to_uppercase())),
+                None => (),
+            }
+        } else {
+            self.verify_answer(input);
+        }
     }
 
-    fn run(&mut self) -> () {
-        while !win(&self.word, &self.formed_word_by_hits) && !lost(self.attempts) {
+    fn run(&mut self) {
+        while self.status() == GameStatus::InProgress && !self.input_closed {
             self.print_game();
+            let previous_stage = self.stage();
             self.prompt_user();
-            clear_screen();
+            if self.stage() > previous_stage {
+                self.scheduler.play(&draw_limb(previous_stage, self.stage()), &mut *self.logger);
+            }
+            self.clear_screen();
+        }
+        if self.batch {
+            match self.status() {
+                GameStatus::Won => print_result("won", &self.word, &mut *self.logger),
+                GameStatus::Lost => print_result("lost", &self.word, &mut *self.logger),
+                GameStatus::InProgress => self.logger.print(format_args!("result incomplete")),
+            }
+            for achievement in self.unlocked_achievements().iter() {
+                print_achievement(achievement, &mut *self.logger);
+            }
+            return;
+        }
+        if self.sound {
+            ring_bell(&mut *self.logger);
+        }
+        match self.status() {
+            GameStatus::Won => self.scheduler.play(&celebrate(&self.word), &mut *self.logger),
+            GameStatus::Lost => self.scheduler.play(&swing(), &mut *self.logger),
+            GameStatus::InProgress => self.logger.print(format_args!("\nInput ended before the game was over")),
         }
-        end_of_game(self.word, &self.formed_word_by_hits, self.attempts);
+        end_of_game(&self.word, &self.formed_word_by_hits, self.attempts, self.lives, &self.unlocked_achievements(), &mut *self.logger);
     }
 }
@@ -0,0 +1,241 @@
+use std::fs::{create_dir_all, read_to_string, write, OpenOptions};
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::leaderboard::score::Score;
+use crate::logger::logger::Logger;
+use crate::random_word::word_list::Difficulty;
+use crate::stats::stats::data_dir;
+
+pub const SCORES_FILE: &str = "scores.csv";
+pub const PROFILES_FILE: &str = "profiles.csv";
+pub const SECONDS_PER_WEEK: u64 = 7 * 24 * 60 * 60;
+/// 1970-01-01 was a Thursday, weeks start on Monday.
+const EPOCH_WEEKDAY_OFFSET: u64 = 3 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreEntry {
+    pub timestamp: u64,
+    pub player: String,
+    pub word: String,
+    pub category: String,
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    pub created: u64,
+    pub games: u32,
+    pub wins: u32,
+    pub total_score: u64,
+    pub best_score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum View {
+    AllTime,
+    Weekly,
+    Category(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub player: String,
+    pub games: u32,
+    pub wins: u32,
+    pub score: u64,
+}
+
+/// Commas separate the columns of the files, so they cannot be part of a name.
+pub fn clean_name(name: &str) -> String {
+    name.trim().replace(',', " ")
+}
+
+/// Names are kept as first written but compared without case, so `Ana` and `ana` are the same player.
+pub fn same_player(name: &str, other: &str) -> bool {
+    clean_name(name).to_lowercase() == clean_name(other).to_lowercase()
+}
+
+impl ScoreEntry {
+    pub fn new(timestamp: u64, player: &str, word: &str, category: &str, won: bool, score: &Score) -> ScoreEntry {
+        ScoreEntry {
+            timestamp,
+            player: clean_name(player),
+            word: word.to_lowercase(),
+            category: clean_name(category),
+            difficulty: Difficulty::of(word),
+            won,
+            score: score.total,
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{},{},{},{},{},{},{}", self.timestamp, self.player, self.word, self.category, self.difficulty, if self.won { "won" } else { "lost" }, self.score)
+    }
+
+    pub fn from_line(line: &str) -> Option<ScoreEntry> {
+        let columns: Vec<&str> = line.trim().split(',').collect();
+        let [timestamp, player, word, category, difficulty, result, score] = columns.as_slice() else {
+            return None;
+        };
+        Some(ScoreEntry {
+            timestamp: timestamp.parse().ok()?,
+            player: player.to_string(),
+            word: word.to_string(),
+            category: category.to_string(),
+            difficulty: difficulty.parse().ok()?,
+            won: *result == "won",
+            score: score.parse().ok()?,
+        })
+    }
+}
+
+impl Profile {
+    pub fn new(name: &str, created: u64) -> Profile {
+        Profile { name: clean_name(name), created, games: 0, wins: 0, total_score: 0, best_score: 0 }
+    }
+
+    pub fn add(&mut self, entry: &ScoreEntry) {
+        self.games += 1;
+        if entry.won {
+            self.wins += 1;
+        }
+        self.total_score += entry.score as u64;
+        self.best_score = self.best_score.max(entry.score);
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{},{},{},{},{},{}", self.name, self.created, self.games, self.wins, self.total_score, self.best_score)
+    }
+
+    pub fn from_line(line: &str) -> Option<Profile> {
+        let columns: Vec<&str> = line.trim().split(',').collect();
+        let [name, created, games, wins, total_score, best_score] = columns.as_slice() else {
+            return None;
+        };
+        Some(Profile {
+            name: name.to_string(),
+            created: created.parse().ok()?,
+            games: games.parse().ok()?,
+            wins: wins.parse().ok()?,
+            total_score: total_score.parse().ok()?,
+            best_score: best_score.parse().ok()?,
+        })
+    }
+}
+
+impl View {
+    pub fn title(&self) -> String {
+        match self {
+            View::AllTime => "Leaderboard - all time".to_string(),
+            View::Weekly => "Leaderboard - this week".to_string(),
+            View::Category(category) => format!("Leaderboard - {category}"),
+        }
+    }
+
+    pub fn includes(&self, entry: &ScoreEntry, now: u64) -> bool {
+        match self {
+            View::AllTime => true,
+            View::Weekly => entry.timestamp >= week_start(now),
+            View::Category(category) => entry.category.eq_ignore_ascii_case(category),
+        }
+    }
+}
+
+pub fn week_start(timestamp: u64) -> u64 {
+    let shifted = timestamp + EPOCH_WEEKDAY_OFFSET;
+    (shifted - shifted % SECONDS_PER_WEEK).saturating_sub(EPOCH_WEEKDAY_OFFSET)
+}
+
+/// Players ordered by the points they scored in the view, then by wins and name.
+pub fn standings(entries: &[ScoreEntry], view: &View, now: u64) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = Vec::new();
+    for entry in entries.iter().filter(|entry| view.includes(entry, now)) {
+        let index = match standings.iter().position(|standing| same_player(&standing.player, &entry.player)) {
+            Some(index) => index,
+            None => {
+                standings.push(Standing { player: entry.player.clone(), games: 0, wins: 0, score: 0 });
+                standings.len() - 1
+            }
+        };
+        let standing = &mut standings[index];
+        standing.games += 1;
+        standing.wins += entry.won as u32;
+        standing.score += entry.score as u64;
+    }
+    standings.sort_by(|a, b| b.score.cmp(&a.score).then(b.wins.cmp(&a.wins)).then(a.player.cmp(&b.player)));
+    standings
+}
+
+pub fn scores_path() -> PathBuf {
+    data_dir().join(SCORES_FILE)
+}
+
+pub fn profiles_path() -> PathBuf {
+    data_dir().join(PROFILES_FILE)
+}
+
+pub fn record_score(path: &Path, entry: &ScoreEntry) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", entry.to_line())
+}
+
+pub fn load_scores(path: &Path) -> Result<Vec<ScoreEntry>, Error> {
+    match read_to_string(path) {
+        Ok(content) => Ok(content.lines().filter_map(ScoreEntry::from_line).collect()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn load_profiles(path: &Path) -> Result<Vec<Profile>, Error> {
+    match read_to_string(path) {
+        Ok(content) => Ok(content.lines().filter_map(Profile::from_line).collect()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Adds a game to the profile of its player, creating the profile on their first game.
+pub fn update_profile(path: &Path, entry: &ScoreEntry) -> Result<Profile, Error> {
+    let mut profiles = load_profiles(path)?;
+    let index = match profiles.iter().position(|profile| same_player(&profile.name, &entry.player)) {
+        Some(index) => index,
+        None => {
+            profiles.push(Profile::new(&entry.player, entry.timestamp));
+            profiles.len() - 1
+        }
+    };
+    profiles[index].add(entry);
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let content: String = profiles.iter().map(|profile| format!("{}\n", profile.to_line())).collect();
+    write(path, content)?;
+    Ok(profiles[index].clone())
+}
+
+pub fn print_leaderboard(standings: &[Standing], view: &View, limit: usize, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", view.title()));
+    if standings.is_empty() {
+        logger.print(format_args!("No games yet"));
+        return;
+    }
+    for (index, standing) in standings.iter().take(limit).enumerate() {
+        logger.print(format_args!("{:>2}. {:<16} {:>6} pts  {} games  {} wins", index + 1, standing.player, standing.score, standing.games, standing.wins));
+    }
+}
+
+pub fn print_profile(profile: &Profile, logger: &mut dyn Logger) {
+    logger.print(format_args!("Player: {}", profile.name));
+    logger.print(format_args!("Games: {}", profile.games));
+    logger.print(format_args!("Wins: {}", profile.wins));
+    logger.print(format_args!("Total score: {}", profile.total_score));
+    logger.print(format_args!("Best score: {}", profile.best_score));
+}
@@ -0,0 +1,89 @@
+use crate::constants::attempts::SIX;
+use crate::game::game::{GameStatus, HangmanGame};
+use crate::random_word::word_list::Difficulty;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreRules {
+    pub easy: u32,
+    pub medium: u32,
+    pub hard: u32,
+    /// Points for each life left out of the default six, so playing with more lives does not earn more.
+    pub per_life_left: u32,
+    pub speed_bonus: u32,
+    /// Games won within this many seconds earn part of the speed bonus, the faster the more.
+    pub speed_window: u64,
+    pub per_hint: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreInput {
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub lives: u8,
+    pub lives_left: u8,
+    pub seconds: u64,
+    pub hints: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Score {
+    pub base: u32,
+    pub lives_bonus: u32,
+    pub speed_bonus: u32,
+    pub hint_penalty: u32,
+    pub total: u32,
+}
+
+impl Default for ScoreRules {
+    fn default() -> Self {
+        ScoreRules { easy: 100, medium: 200, hard: 300, per_life_left: 20, speed_bonus: 100, speed_window: 120, per_hint: 50 }
+    }
+}
+
+impl ScoreInput {
+    pub fn of(game: &HangmanGame) -> ScoreInput {
+        ScoreInput {
+            difficulty: Difficulty::of(game.word()),
+            won: game.status() == GameStatus::Won,
+            lives: game.lives(),
+            lives_left: game.lives().saturating_sub(game.attempts()),
+            seconds: game.elapsed_millis() / 1000,
+            hints: game.hints_used(),
+        }
+    }
+}
+
+impl ScoreRules {
+    pub fn base(&self, difficulty: Difficulty) -> u32 {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Medium => self.medium,
+            Difficulty::Hard => self.hard,
+        }
+    }
+
+    /// Lost games score nothing, and hints never take a won game below zero.
+    pub fn score(&self, input: &ScoreInput) -> Score {
+        if !input.won {
+            return Score::default();
+        }
+        let base = self.base(input.difficulty);
+        let lives_bonus = match input.lives {
+            0 => 0,
+            lives => self.per_life_left * SIX as u32 * input.lives_left.min(lives) as u32 / lives as u32,
+        };
+        let speed_bonus = match self.speed_window {
+            0 => 0,
+            window => (self.speed_bonus as u64 * window.saturating_sub(input.seconds) / window) as u32,
+        };
+        let hint_penalty = self.per_hint * input.hints as u32;
+        let total = (base + lives_bonus + speed_bonus).saturating_sub(hint_penalty);
+        Score { base, lives_bonus, speed_bonus, hint_penalty, total }
+    }
+}
+
+impl Score {
+    pub fn describe(&self) -> String {
+        format!("{} (base {}, lives +{}, speed +{}, hints -{})", self.total, self.base, self.lives_bonus, self.speed_bonus, self.hint_penalty)
+    }
+}
@@ -1,27 +1,126 @@
-use std::io::{BufRead, stdin, stdout, Write};
+#[cfg(feature = "terminal")]
+use std::io::stdin;
+use std::io::{BufRead, stdout, Write};
+use crate::achievements::achievements::{print_toast, Achievement};
 use crate::constants::attempts::SIX;
-use crate::logger::logger::{Logger, StdoutLogger};
+use crate::logger::logger::Logger;
 use crate::puppet::gibbet::Gibbet;
 
+#[allow(clippy::module_inception)]
+pub mod achievements {
+    pub mod achievements;
+}
+
+#[allow(clippy::module_inception)]
+pub mod accessibility {
+    pub mod accessibility;
+}
+
+#[allow(clippy::module_inception)]
+pub mod animation {
+    pub mod animation;
+}
+
+#[allow(clippy::module_inception)]
+pub mod batch {
+    pub mod batch;
+}
+
+#[allow(clippy::module_inception)]
+pub mod bot {
+    pub mod bot;
+    pub mod transport;
+}
+
+#[cfg(feature = "cli")]
+#[allow(clippy::module_inception)]
+pub mod cli {
+    pub mod cli;
+    pub mod commands;
+}
+
+#[allow(clippy::module_inception)]
+pub mod config {
+    pub mod config;
+}
+
 pub mod constants {
     pub mod attempts;
     pub mod gibbet_phases;
 }
 
+#[allow(clippy::module_inception)]
 pub mod game {
     pub mod game;
 }
 
+#[allow(clippy::module_inception)]
+pub mod leaderboard {
+    pub mod leaderboard;
+    pub mod score;
+}
+
+#[allow(clippy::module_inception)]
 pub mod logger {
+    pub mod event;
+    pub mod json_lines;
     pub mod logger;
+    pub mod sinks;
 }
 
 pub mod puppet {
     pub mod gibbet;
 }
 
+#[allow(clippy::module_inception)]
 pub mod random_word {
+    pub mod blocklist;
+    pub mod import;
+    pub mod index;
     pub mod random_word;
+    pub mod validate;
+    pub mod word_list;
+}
+
+#[allow(clippy::module_inception)]
+pub mod replay {
+    pub mod replay;
+}
+
+#[cfg(feature = "server")]
+#[allow(clippy::module_inception)]
+pub mod server {
+    pub mod http;
+    pub mod lobby;
+    pub mod room;
+    pub mod server;
+    pub mod web;
+}
+
+#[allow(clippy::module_inception)]
+pub mod solver {
+    pub mod solver;
+}
+
+#[allow(clippy::module_inception)]
+pub mod stats {
+    pub mod stats;
+}
+
+#[allow(clippy::module_inception)]
+pub mod survival {
+    pub mod survival;
+}
+
+#[allow(clippy::module_inception)]
+pub mod timer {
+    pub mod timer;
+}
+
+#[cfg(feature = "wasm")]
+#[allow(clippy::module_inception)]
+pub mod wasm {
+    pub mod wasm;
 }
 
 pub fn word_contains_letter(letter: String, word: String) -> bool {
@@ -30,10 +129,11 @@ pub fn word_contains_letter(letter: String, word: String) -> bool {
     word.contains(&letter)
 }
 
-pub fn get_occurrences(letter: String, word: &'static str) -> Vec<usize> {
+pub fn get_occurrences(letter: String, word: &str) -> Vec<usize> {
+    let letter: String = letter.to_lowercase();
     let mut occurrences: Vec<usize> = Vec::new();
     for (index, character) in word.chars().enumerate() {
-        if letter.eq_ignore_ascii_case(character.to_string().as_str()) {
+        if letter == character.to_lowercase().to_string() {
             occurrences.push(index);
         }
     };
@@ -48,10 +148,16 @@ pub fn get_blank_chars(size: usize) -> Vec<String> {
     blank_chars
 }
 
-pub fn print_sequence(separator: String, sequence: Vec<String>, logger: &mut dyn Logger) -> () {
+pub fn print_sequence(separator: String, sequence: Vec<String>, logger: &mut dyn Logger) {
     logger.print(format_args!("{}", sequence.iter().map(|n| format!("{n}{separator}")).fold(String::new(), |acc, arg| acc + arg.as_str())));
 }
 
+pub fn print_word_hits(formed_word_by_hits: &[String], logger: &mut dyn Logger) {
+    let blank_chars: Vec<String> = get_blank_chars(formed_word_by_hits.len());
+    print_sequence(" ".to_string(), formed_word_by_hits.to_vec(), logger);
+    print_sequence("_".to_string(), blank_chars, logger);
+}
+
 pub fn prompt<R, W>(mut reader: R, mut writer: W, question: &str) -> String
     where
         R: BufRead,
@@ -64,48 +170,47 @@ pub fn prompt<R, W>(mut reader: R, mut writer: W, question: &str) -> String
     s
 }
 
-#[cfg(not(tarpaulin_include))]
+#[cfg(all(feature = "terminal", not(tarpaulin_include)))]
 fn get_answer() -> String {
     let stdio = stdin();
     let input = stdio.lock();
     let output = stdout();
-    let answer = prompt(input, output, "Choose a letter: ");
-    answer
+    prompt(input, output, "Choose a letter: ")
 }
 
-pub fn print_gibbet(logger: &mut dyn Logger) -> () {
+pub fn print_gibbet(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::Gibbet));
 }
 
-pub fn print_head(logger: &mut dyn Logger) -> () {
+pub fn print_head(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::Head));
 }
 
-pub fn print_head_and_right_arm(logger: &mut dyn Logger) -> () {
+pub fn print_head_and_right_arm(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::HeadAndRightArm));
 }
 
-pub fn print_head_right_arm_and_left_arm(logger: &mut dyn Logger) -> () {
+pub fn print_head_right_arm_and_left_arm(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::HeadRightArmAndLeftArm));
 }
 
-pub fn print_head_right_arm_left_arm_and_stem(logger: &mut dyn Logger) -> () {
+pub fn print_head_right_arm_left_arm_and_stem(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::HeadRightArmLeftArmAndStem));
 }
 
-pub fn print_head_right_arm_left_arm_stem_and_right_leg(logger: &mut dyn Logger) -> () {
+pub fn print_head_right_arm_left_arm_stem_and_right_leg(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::HeadRightArmLeftArmStemAndRightLeg));
 }
 
-pub fn print_head_right_arm_left_arm_stem_right_leg_and_left_leg(logger: &mut dyn Logger) -> () {
+pub fn print_head_right_arm_left_arm_stem_right_leg_and_left_leg(logger: &mut dyn Logger) {
     logger.print(format_args!("{}", Gibbet::HeadRightArmLeftArmStemRightLegAndLeftLeg));
 }
 
-pub fn print_default(logger: &mut dyn Logger) -> () {
+pub fn print_default(logger: &mut dyn Logger) {
     logger.print(format_args!("Invalid"));
 }
 
-pub fn print_gibbet_status(attempts: u8, logger: &mut dyn Logger) -> () {
+pub fn print_gibbet_status(attempts: u8, logger: &mut dyn Logger) {
     match attempts {
         0 => print_gibbet(logger),
         1 => print_head(logger),
@@ -118,11 +223,30 @@ pub fn print_gibbet_status(attempts: u8, logger: &mut dyn Logger) -> () {
     }
 }
 
-pub fn clear_screen() -> () {
-    clearscreen::clear().unwrap();
+pub fn compact_gibbet(attempts: u8, lives: u8) -> String {
+    let used = attempts.min(lives) as usize;
+    let left = lives.saturating_sub(attempts) as usize;
+    format!("[{}{}] {attempts}/{lives}", "#".repeat(used), "-".repeat(left))
+}
+
+pub fn print_compact_gibbet(attempts: u8, lives: u8, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", compact_gibbet(attempts, lives)));
 }
 
-pub fn win(word: &'static str, formed_word_by_hits: &Vec<String>) -> bool {
+pub fn ring_bell(logger: &mut dyn Logger) {
+    logger.print(format_args!("\x07"));
+}
+
+#[cfg(feature = "terminal")]
+pub fn clear_screen() {
+    clearscreen::clear().ok();
+}
+
+/// Without a terminal there is no screen to clear.
+#[cfg(not(feature = "terminal"))]
+pub fn clear_screen() {}
+
+pub fn win(word: &str, formed_word_by_hits: &[String]) -> bool {
     let word: String = word.to_lowercase();
     word.eq(&formed_word_by_hits.concat())
 }
@@ -131,17 +255,30 @@ pub fn lost(attempts: u8) -> bool {
     attempts == SIX
 }
 
-pub fn end(status: &str, word: &'static str, logger: &mut dyn Logger) -> () {
+pub fn lost_with_lives(attempts: u8, lives: u8) -> bool {
+    attempts >= lives
+}
+
+pub fn gibbet_stage(attempts: u8, lives: u8) -> u8 {
+    if lives == 0 || attempts >= lives {
+        return SIX;
+    }
+    (attempts as u16 * SIX as u16).div_ceil(lives as u16) as u8
+}
+
+pub fn end(status: &str, word: &str, logger: &mut dyn Logger) {
     logger.print(format_args!("You {status}"));
     logger.print(format_args!("\nThe word is: {word}"));
 }
 
 #[cfg(not(tarpaulin_include))]
-pub fn end_of_game(word: &'static str, formed_word_by_hits: &Vec<String>, attempts: u8) -> () {
-    let mut std_out_logger = StdoutLogger;
-    if win(word, &formed_word_by_hits) {
-        end("WON", word, &mut std_out_logger);
-    } else if lost(attempts) {
-        end("LOSE", word, &mut std_out_logger);
+pub fn end_of_game(word: &str, formed_word_by_hits: &[String], attempts: u8, lives: u8, unlocked: &[Achievement], logger: &mut dyn Logger) {
+    if win(word, formed_word_by_hits) {
+        end("WON", word, logger);
+    } else if lost_with_lives(attempts, lives) {
+        end("LOSE", word, logger);
+    }
+    for achievement in unlocked.iter() {
+        print_toast(achievement, logger);
     }
 }
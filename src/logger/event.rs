@@ -0,0 +1,88 @@
+use std::fmt;
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::logger::logger::Logger;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum GameEvent {
+    /// `attempts` are the lives already lost when the game starts, as in the later words of a survival run.
+    GameStarted {
+        word: String,
+        lives: u8,
+        #[serde(default)]
+        attempts: u8,
+    },
+    LetterGuessed { letter: String, hit: bool, positions: Vec<usize> },
+    WordGuessed { word: String, correct: bool },
+    HintUsed { letter: String, positions: Vec<usize> },
+    GuessTimedOut { attempts: u8 },
+    GuessUndone { attempts: u8 },
+    GameWon { attempts: u8, hints: u8 },
+    GameLost { attempts: u8, hints: u8 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub timestamp: u64,
+    #[serde(flatten)]
+    pub event: GameEvent,
+}
+
+impl EventRecord {
+    pub fn new(event: GameEvent) -> EventRecord {
+        EventRecord { timestamp: now_millis(), event }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(line: &str) -> Result<EventRecord, serde_json::Error> {
+        serde_json::from_str(line)
+    }
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_millis() as u64).unwrap_or_default()
+}
+
+/// `SystemTime` panics in the browser, where the clock comes from JavaScript.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub fn now_millis() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+#[derive(Default)]
+pub struct EventLog {
+    records: Vec<EventRecord>,
+    sinks: Vec<Box<dyn Logger>>,
+}
+
+impl fmt::Debug for EventLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventLog").field("records", &self.records).field("sinks", &self.sinks.len()).finish()
+    }
+}
+
+impl EventLog {
+    pub fn add_sink(&mut self, sink: Box<dyn Logger>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        let record = EventRecord::new(event);
+        for sink in self.sinks.iter_mut() {
+            sink.log_event(&record);
+        }
+        self.records.push(record);
+    }
+
+    pub fn records(&self) -> &[EventRecord] {
+        &self.records
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Arguments;
+use std::fs::{create_dir_all, File, OpenOptions};
+use std::io::{Error, Write};
+use std::path::Path;
+
+use crate::logger::event::EventRecord;
+use crate::logger::logger::Logger;
+
+pub struct JsonLinesLogger<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesLogger<W> {
+    pub fn new(writer: W) -> JsonLinesLogger<W> {
+        JsonLinesLogger { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl JsonLinesLogger<File> {
+    pub fn create(path: &Path) -> Result<JsonLinesLogger<File>, Error> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            create_dir_all(parent)?;
+        }
+        Ok(JsonLinesLogger::new(OpenOptions::new().create(true).append(true).open(path)?))
+    }
+}
+
+impl<W: Write + Send> Logger for JsonLinesLogger<W> {
+    fn print(&mut self, _value: Arguments<'_>) {}
+
+    fn log_event(&mut self, record: &EventRecord) {
+        let result = writeln!(self.writer, "{}", record.to_json()).and_then(|_| self.writer.flush());
+        if let Err(error) = result {
+            eprintln!("warning: could not write the event log: {error}");
+        }
+    }
+}
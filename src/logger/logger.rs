@@ -1,7 +1,61 @@
+use std::fmt;
 use std::fmt::Arguments;
+use std::io::{stdout, Write};
+use std::str::FromStr;
 
-pub trait Logger {
+use crate::logger::event::EventRecord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "debug" => Ok(Level::Debug),
+            "info" => Ok(Level::Info),
+            "warn" | "warning" => Ok(Level::Warn),
+            "error" => Ok(Level::Error),
+            _ => Err(format!("unknown level `{value}` (expected debug, info, warn or error)")),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+        };
+        write!(f, "{name}")
+    }
+}
+
+pub trait Logger: Send {
     fn print(&mut self, value: Arguments<'_>);
+
+    /// Loggers only show what the player should see unless they override this.
+    fn log(&mut self, level: Level, value: Arguments<'_>) {
+        if level >= Level::Info {
+            self.print(value);
+        }
+    }
+
+    fn log_event(&mut self, _record: &EventRecord) {}
+
+    /// Rewrites the line `lines_up` lines above the cursor and puts the cursor back, so what the player is typing stays
+    /// in view. Loggers that are not a terminal print it as a new line.
+    fn reprint(&mut self, _lines_up: u16, value: Arguments<'_>) {
+        self.print(value);
+    }
 }
 
 pub struct StdoutLogger;
@@ -10,4 +64,9 @@ impl Logger for StdoutLogger {
     fn print(&mut self, value: Arguments<'_>) {
         println!("{}", value);
     }
+
+    fn reprint(&mut self, lines_up: u16, value: Arguments<'_>) {
+        print!("\x1b7\x1b[{lines_up}A\r\x1b[2K{value}\x1b8");
+        let _ = stdout().flush();
+    }
 }
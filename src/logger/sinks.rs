@@ -0,0 +1,168 @@
+use std::fmt::Arguments;
+use std::fs::{create_dir_all, File, OpenOptions};
+use std::io::{Error, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::logger::event::EventRecord;
+use crate::logger::logger::{Level, Logger};
+
+#[derive(Default)]
+pub struct MultiLogger {
+    sinks: Vec<Box<dyn Logger>>,
+}
+
+impl MultiLogger {
+    pub fn new() -> MultiLogger {
+        MultiLogger::default()
+    }
+
+    pub fn with(mut self, sink: Box<dyn Logger>) -> MultiLogger {
+        self.add_sink(sink);
+        self
+    }
+
+    pub fn add_sink(&mut self, sink: Box<dyn Logger>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+}
+
+impl Logger for MultiLogger {
+    fn print(&mut self, value: Arguments<'_>) {
+        for sink in self.sinks.iter_mut() {
+            sink.print(value);
+        }
+    }
+
+    fn log(&mut self, level: Level, value: Arguments<'_>) {
+        for sink in self.sinks.iter_mut() {
+            sink.log(level, value);
+        }
+    }
+
+    fn log_event(&mut self, record: &EventRecord) {
+        for sink in self.sinks.iter_mut() {
+            sink.log_event(record);
+        }
+    }
+
+    fn reprint(&mut self, lines_up: u16, value: Arguments<'_>) {
+        for sink in self.sinks.iter_mut() {
+            sink.reprint(lines_up, value);
+        }
+    }
+}
+
+pub struct LevelFilter {
+    min: Level,
+    inner: Box<dyn Logger>,
+}
+
+impl LevelFilter {
+    pub fn new(min: Level, inner: Box<dyn Logger>) -> LevelFilter {
+        LevelFilter { min, inner }
+    }
+}
+
+impl Logger for LevelFilter {
+    fn print(&mut self, value: Arguments<'_>) {
+        self.log(Level::Info, value);
+    }
+
+    fn log(&mut self, level: Level, value: Arguments<'_>) {
+        if level >= self.min {
+            self.inner.log(level, value);
+        }
+    }
+
+    fn log_event(&mut self, record: &EventRecord) {
+        self.inner.log_event(record);
+    }
+
+    fn reprint(&mut self, lines_up: u16, value: Arguments<'_>) {
+        if Level::Info >= self.min {
+            self.inner.reprint(lines_up, value);
+        }
+    }
+}
+
+pub struct FileLogger<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> FileLogger<W> {
+    pub fn new(writer: W) -> FileLogger<W> {
+        FileLogger { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl FileLogger<File> {
+    pub fn create(path: &Path) -> Result<FileLogger<File>, Error> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            create_dir_all(parent)?;
+        }
+        Ok(FileLogger::new(OpenOptions::new().create(true).append(true).open(path)?))
+    }
+}
+
+impl<W: Write + Send> Logger for FileLogger<W> {
+    fn print(&mut self, value: Arguments<'_>) {
+        self.log(Level::Info, value);
+    }
+
+    fn log(&mut self, level: Level, value: Arguments<'_>) {
+        let result = writeln!(self.writer, "[{level}] {value}").and_then(|_| self.writer.flush());
+        if let Err(error) = result {
+            eprintln!("warning: could not write the log file: {error}");
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryLogger {
+    lines: Arc<Mutex<Vec<(Level, String)>>>,
+}
+
+impl MemoryLogger {
+    pub fn new() -> MemoryLogger {
+        MemoryLogger::default()
+    }
+
+    pub fn lines(&self) -> Vec<(Level, String)> {
+        self.lines.lock().map(|lines| lines.clone()).unwrap_or_default()
+    }
+
+    pub fn messages(&self, min: Level) -> Vec<String> {
+        self.lines().into_iter().filter(|(level, _)| *level >= min).map(|(_, line)| line).collect()
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.clear();
+        }
+    }
+}
+
+impl Logger for MemoryLogger {
+    fn print(&mut self, value: Arguments<'_>) {
+        self.log(Level::Info, value);
+    }
+
+    fn log(&mut self, level: Level, value: Arguments<'_>) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.push((level, value.to_string()));
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use jogo_da_forca::random_word::random_word::get_random_word;
-use jogo_da_forca::game::game::{Game, HangmanGame};
+use std::process::ExitCode;
 
-fn main() {
-    let mut game: HangmanGame = Game::new(get_random_word(), Vec::new(), Vec::new(), Vec::new(), 0);
-    game.play();
+use clap::Parser;
+use jogo_da_forca::cli::cli::Cli;
+use jogo_da_forca::cli::commands::run;
+
+fn main() -> ExitCode {
+    ExitCode::from(run(Cli::parse()))
 }
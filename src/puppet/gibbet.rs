@@ -12,6 +12,21 @@ pub enum Gibbet {
     HeadRightArmLeftArmStemRightLegAndLeftLeg,
 }
 
+impl Gibbet {
+    pub fn from_attempts(attempts: u8) -> Option<Gibbet> {
+        match attempts {
+            0 => Some(Self::Gibbet),
+            1 => Some(Self::Head),
+            2 => Some(Self::HeadAndRightArm),
+            3 => Some(Self::HeadRightArmAndLeftArm),
+            4 => Some(Self::HeadRightArmLeftArmAndStem),
+            5 => Some(Self::HeadRightArmLeftArmStemAndRightLeg),
+            6 => Some(Self::HeadRightArmLeftArmStemRightLegAndLeftLeg),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Gibbet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
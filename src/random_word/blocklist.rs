@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::read_to_string;
+use std::io::Error;
+use std::path::Path;
+
+use crate::random_word::word_list::{match_key, Word};
+
+const BUILT_IN_BLOCKLISTS: [(&str, &str); 2] = [
+    ("en", include_str!("../../assets/blocklists/en.txt")),
+    ("pt", include_str!("../../assets/blocklists/pt.txt")),
+];
+
+/// Words that must not be played, compared with `match_key` so neither case nor accents let them through.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Blocklist {
+    words: HashSet<String>,
+}
+
+impl Blocklist {
+    /// One word per line, like a word list: blank lines, `#` comments and any column after the word are ignored.
+    pub fn parse(content: &str) -> Blocklist {
+        let words = content.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| match_key(line.split(',').next().unwrap_or_default()))
+            .filter(|word| !word.is_empty())
+            .collect();
+        Blocklist { words }
+    }
+
+    pub fn extend(&mut self, other: Blocklist) {
+        self.words.extend(other.words);
+    }
+
+    pub fn contains(&self, text: &str) -> bool {
+        self.words.contains(&match_key(text))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+pub fn load_blocklist(path: &Path) -> Result<Blocklist, Error> {
+    Ok(Blocklist::parse(&read_to_string(path)?))
+}
+
+/// The blocklists of each language, applied to the words as they are loaded so a blocked word is never drawn.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Blocklists {
+    lists: HashMap<String, Blocklist>,
+}
+
+impl Blocklists {
+    /// The built-in lists of offensive words, the default unless the player opts out.
+    pub fn family_friendly() -> Blocklists {
+        let mut blocklists = Blocklists::default();
+        for (language, content) in BUILT_IN_BLOCKLISTS.iter() {
+            blocklists.add(language, Blocklist::parse(content));
+        }
+        blocklists
+    }
+
+    pub fn add(&mut self, language: &str, blocklist: Blocklist) {
+        self.lists.entry(language.to_lowercase()).or_default().extend(blocklist);
+    }
+
+    pub fn get(&self, language: &str) -> Option<&Blocklist> {
+        self.lists.get(&language.to_lowercase())
+    }
+
+    pub fn allows(&self, word: &Word) -> bool {
+        self.get(&word.language).is_none_or(|blocklist| !blocklist.contains(&word.text))
+    }
+
+    pub fn filter(&self, words: Vec<Word>) -> Vec<Word> {
+        words.into_iter().filter(|word| self.allows(word)).collect()
+    }
+}
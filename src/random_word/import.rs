@@ -0,0 +1,318 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::random_word::blocklist::Blocklist;
+use crate::random_word::validate::ValidationRules;
+use crate::random_word::word_list::{match_key, Weighting, WEIGHTING_DIRECTIVE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A hunspell `.dic` file: a word count, then `word/FLAGS` lines.
+    Hunspell,
+    /// One word per line, like `/usr/share/dict/words`.
+    Plain,
+    /// `word,count` lines, as in the frequency lists built from subtitles or Wikipedia.
+    Frequency,
+}
+
+impl Format {
+    /// `.dic` files are hunspell dictionaries and `.csv`, `.tsv` files frequency lists, anything else a plain list.
+    pub fn of(path: &Path) -> Format {
+        match path.extension().and_then(|extension| extension.to_str()).map(|extension| extension.to_lowercase()).as_deref() {
+            Some("dic") => Format::Hunspell,
+            Some("csv" | "tsv") => Format::Frequency,
+            _ => Format::Plain,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "hunspell" => Ok(Format::Hunspell),
+            "plain" => Ok(Format::Plain),
+            "frequency" => Ok(Format::Frequency),
+            _ => Err(format!("unknown format `{value}` (expected hunspell, plain or frequency)")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Hunspell => "hunspell",
+                Self::Plain => "plain",
+                Self::Frequency => "frequency",
+            }
+        )
+    }
+}
+
+/// A word read from a dictionary, with its number of occurrences when the source counts them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub text: String,
+    pub frequency: Option<u64>,
+}
+
+impl Entry {
+    pub fn new(text: &str, frequency: Option<u64>) -> Entry {
+        Entry { text: text.to_string(), frequency }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagFormat {
+    Char,
+    Long,
+    Num,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Any,
+    Letter(char),
+    Set(Vec<char>, bool),
+}
+
+impl Condition {
+    fn parse(pattern: &str) -> Vec<Condition> {
+        let mut conditions: Vec<Condition> = Vec::new();
+        let mut letters = pattern.chars();
+        while let Some(letter) = letters.next() {
+            conditions.push(match letter {
+                '.' => Condition::Any,
+                '[' => {
+                    let set: String = letters.by_ref().take_while(|letter| *letter != ']').collect();
+                    match set.strip_prefix('^') {
+                        Some(negated) => Condition::Set(negated.chars().collect(), true),
+                        None => Condition::Set(set.chars().collect(), false),
+                    }
+                }
+                _ => Condition::Letter(letter),
+            });
+        }
+        conditions
+    }
+
+    fn matches(&self, letter: char) -> bool {
+        match self {
+            Condition::Any => true,
+            Condition::Letter(expected) => *expected == letter,
+            Condition::Set(letters, negated) => letters.contains(&letter) != *negated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    condition: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AffixClass {
+    suffix: bool,
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+/// The prefix and suffix rules of a hunspell `.aff` file, enough to expand the flags of a `.dic` file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affixes {
+    flag_format: FlagFormat,
+    classes: HashMap<String, AffixClass>,
+}
+
+impl Default for Affixes {
+    fn default() -> Self {
+        Affixes { flag_format: FlagFormat::Char, classes: HashMap::new() }
+    }
+}
+
+impl Affixes {
+    /// Reads the `FLAG`, `PFX` and `SFX` lines, ignoring every other setting.
+    pub fn parse(content: &str) -> Affixes {
+        let mut affixes = Affixes::default();
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", "long"] => affixes.flag_format = FlagFormat::Long,
+                ["FLAG", "num"] => affixes.flag_format = FlagFormat::Num,
+                [kind @ ("PFX" | "SFX"), flag, cross_product @ ("Y" | "N"), count] if count.parse::<usize>().is_ok() => {
+                    affixes.classes.insert(flag.to_string(), AffixClass { suffix: *kind == "SFX", cross_product: *cross_product == "Y", rules: Vec::new() });
+                }
+                ["PFX" | "SFX", flag, strip, add, rest @ ..] => {
+                    if let Some(class) = affixes.classes.get_mut(*flag) {
+                        let zero = |affix: &str| if affix == "0" { String::new() } else { affix.to_string() };
+                        // Continuation flags after the `/` would need a second round of expansion, the base form is enough.
+                        let add = add.split('/').next().unwrap_or_default();
+                        let condition = Condition::parse(rest.first().copied().unwrap_or("."));
+                        class.rules.push(AffixRule { strip: zero(strip), add: zero(add), condition });
+                    }
+                }
+                _ => (),
+            }
+        }
+        affixes
+    }
+
+    fn flags(&self, flags: &str) -> Vec<String> {
+        match self.flag_format {
+            FlagFormat::Char => flags.chars().map(|flag| flag.to_string()).collect(),
+            FlagFormat::Long => flags.chars().collect::<Vec<char>>().chunks(2).map(|flag| flag.iter().collect()).collect(),
+            FlagFormat::Num => flags.split(',').map(|flag| flag.trim().to_string()).collect(),
+        }
+    }
+
+    fn apply(class: &AffixClass, word: &str) -> Vec<String> {
+        let letters: Vec<char> = word.chars().collect();
+        class.rules.iter()
+            .filter(|rule| rule.condition.len() <= letters.len())
+            .filter(|rule| {
+                let checked = if class.suffix { &letters[letters.len() - rule.condition.len()..] } else { &letters[..rule.condition.len()] };
+                rule.condition.iter().zip(checked.iter()).all(|(condition, letter)| condition.matches(*letter))
+            })
+            .filter_map(|rule| match class.suffix {
+                true => word.strip_suffix(rule.strip.as_str()).map(|stem| format!("{stem}{}", rule.add)),
+                false => word.strip_prefix(rule.strip.as_str()).map(|stem| format!("{}{stem}", rule.add)),
+            })
+            .collect()
+    }
+
+    /// The word followed by the forms its flags make, with the prefixes applied to the suffixed forms when both allow it.
+    pub fn expand(&self, word: &str, flags: &str) -> Vec<String> {
+        let classes: Vec<&AffixClass> = self.flags(flags).iter().filter_map(|flag| self.classes.get(flag)).collect();
+        let mut forms = vec![word.to_string()];
+        let mut suffixed: Vec<String> = Vec::new();
+        for class in classes.iter().filter(|class| class.suffix) {
+            let new = Affixes::apply(class, word);
+            if class.cross_product {
+                suffixed.extend(new.iter().cloned());
+            }
+            forms.extend(new);
+        }
+        for class in classes.iter().filter(|class| !class.suffix) {
+            forms.extend(Affixes::apply(class, word));
+            if class.cross_product {
+                for form in suffixed.iter() {
+                    forms.extend(Affixes::apply(class, form));
+                }
+            }
+        }
+        forms
+    }
+}
+
+/// The words of a hunspell `.dic` file, with their affix flags expanded when the `.aff` rules are given and dropped otherwise.
+pub fn parse_hunspell(content: &str, affixes: Option<&Affixes>) -> Vec<Entry> {
+    content.lines()
+        .map(|line| line.trim())
+        // The first line is the number of words.
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && line.parse::<usize>().is_err())
+        .flat_map(|line| {
+            // Morphological fields follow a tab or a space.
+            let line = line.split(['\t', ' ']).next().unwrap_or_default();
+            let (word, flags) = line.split_once('/').unwrap_or((line, ""));
+            match affixes {
+                Some(affixes) => affixes.expand(word, flags),
+                None => vec![word.to_string()],
+            }
+        })
+        .map(|word| Entry::new(&word, None))
+        .collect()
+}
+
+pub fn parse_plain(content: &str) -> Vec<Entry> {
+    content.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| Entry::new(line, None))
+        .collect()
+}
+
+/// `word,count` lines, also separated by a tab or spaces. Lines without a count, such as a header, are skipped.
+pub fn parse_frequency(content: &str) -> Vec<Entry> {
+    content.lines()
+        .filter_map(|line| {
+            let mut columns = line.split([',', ';', '\t', ' ']).map(|column| column.trim()).filter(|column| !column.is_empty());
+            let word = columns.next()?;
+            let frequency = columns.next()?.parse::<u64>().ok()?;
+            Some(Entry::new(word, Some(frequency)))
+        })
+        .collect()
+}
+
+/// What makes a dictionary word a good hangman word.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportFilter {
+    /// The alphabet and lengths the words must fit.
+    pub rules: ValidationRules,
+    /// Fewest occurrences of a word in a frequency list.
+    pub min_frequency: Option<u64>,
+    /// Most words kept, the most frequent ones first.
+    pub limit: Option<usize>,
+}
+
+impl ImportFilter {
+    pub fn new(rules: ValidationRules) -> ImportFilter {
+        ImportFilter { rules, min_frequency: None, limit: None }
+    }
+
+    pub fn accepts(&self, entry: &Entry, blocklist: &Blocklist) -> bool {
+        let length = entry.text.chars().count();
+        // Capitalised words are names and abbreviations.
+        !entry.text.chars().any(char::is_uppercase)
+            && entry.text.chars().all(|letter| self.rules.allows(letter))
+            && (self.rules.min_length..=self.rules.max_length).contains(&length)
+            && self.min_frequency.is_none_or(|min| entry.frequency.is_none_or(|frequency| frequency >= min))
+            && !blocklist.contains(&entry.text)
+    }
+}
+
+/// The entries the filter accepts, without the duplicates left by the affixes, the most frequent first.
+pub fn import_words(entries: Vec<Entry>, filter: &ImportFilter, blocklist: &Blocklist) -> Vec<Entry> {
+    let mut kept: Vec<Entry> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for entry in entries.into_iter().filter(|entry| filter.accepts(entry, blocklist)) {
+        match seen.get(&match_key(&entry.text)) {
+            Some(index) => kept[*index].frequency = kept[*index].frequency.max(entry.frequency),
+            None => {
+                seen.insert(match_key(&entry.text), kept.len());
+                kept.push(entry);
+            }
+        }
+    }
+    // The sort is stable, so sources without counts keep their order.
+    kept.sort_by_key(|entry| Reverse(entry.frequency));
+    if let Some(limit) = filter.limit {
+        kept.truncate(limit);
+    }
+    kept
+}
+
+/// The entries as a word list, one `word,category` line each, followed by the count of the word when there is one.
+///
+/// Counts are read on a logarithmic scale so the most common words do not leave every other word out.
+pub fn format_word_list(entries: &[Entry], category: &str) -> String {
+    let header = match entries.iter().any(|entry| entry.frequency.is_some()) {
+        true => format!("# {WEIGHTING_DIRECTIVE} {}\n", Weighting::Log),
+        false => String::new(),
+    };
+    let lines: String = entries.iter()
+        .map(|entry| match entry.frequency {
+            Some(frequency) => format!("{},{category},{frequency}\n", entry.text),
+            None => format!("{},{category}\n", entry.text),
+        })
+        .collect();
+    header + &lines
+}
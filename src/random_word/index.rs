@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+#[cfg(feature = "index")]
+use std::fs::File;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::str::from_utf8;
+
+#[cfg(feature = "index")]
+use memmap2::Mmap;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::random_word::word_list::{Difficulty, Word, WordFilter};
+
+/// First bytes of an index file, with the version of the format.
+pub const INDEX_MAGIC: &[u8; 8] = b"JDFIDX01";
+pub const INDEX_EXTENSION: &str = "idx";
+
+const HEADER_SIZE: usize = 32;
+const NAME_SIZE: usize = 8;
+const GROUP_SIZE: usize = 16;
+const RECORD_SIZE: usize = 24;
+
+fn u16_at(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+fn f64_at(bytes: &[u8], at: usize) -> f64 {
+    let mut weight = [0; 8];
+    weight.copy_from_slice(&bytes[at..at + 8]);
+    f64::from_le_bytes(weight)
+}
+
+fn difficulty_id(difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Medium => 1,
+        Difficulty::Hard => 2,
+    }
+}
+
+/// Words of the same language, category and length, stored next to each other in the index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Group {
+    language: u16,
+    category: u16,
+    length: u16,
+    difficulty: u8,
+    start: u32,
+    count: u32,
+}
+
+/// Compiles the words into an index, sorted by language, category and length so every combination is one range.
+///
+/// The layout, little-endian: a 32-byte header (magic, word, name and group counts, string table size), the names of the
+/// languages and categories (offset and size in the string table), the groups (language, category, length, difficulty,
+/// first word and number of words), the words (offset and size of the text, category, language, length, difficulty and
+/// weight) and the string table.
+pub fn build_index(words: &[Word]) -> Vec<u8> {
+    let mut strings: Vec<u8> = Vec::new();
+    let mut names: Vec<(u32, u32)> = Vec::new();
+    let mut name_ids: HashMap<String, u16> = HashMap::new();
+    let mut name_id = |name: &str, strings: &mut Vec<u8>| -> u16 {
+        // Names differing only in case share the spelling seen first.
+        *name_ids.entry(name.to_lowercase()).or_insert_with(|| {
+            names.push((strings.len() as u32, name.len() as u32));
+            strings.extend_from_slice(name.as_bytes());
+            (names.len() - 1) as u16
+        })
+    };
+    let mut sorted: Vec<&Word> = words.iter().collect();
+    sorted.sort_by_key(|word| (word.language.to_lowercase(), word.category.to_lowercase(), word.text.chars().count()));
+    let mut records: Vec<u8> = Vec::with_capacity(sorted.len() * RECORD_SIZE);
+    let mut groups: Vec<Group> = Vec::new();
+    for (position, word) in sorted.iter().enumerate() {
+        let language = name_id(&word.language, &mut strings);
+        let category = name_id(&word.category, &mut strings);
+        let length = word.text.chars().count() as u16;
+        let difficulty = difficulty_id(word.difficulty());
+        match groups.last_mut() {
+            Some(group) if (group.language, group.category, group.length) == (language, category, length) => group.count += 1,
+            _ => groups.push(Group { language, category, length, difficulty, start: position as u32, count: 1 }),
+        }
+        records.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+        records.extend_from_slice(&(word.text.len() as u32).to_le_bytes());
+        records.extend_from_slice(&category.to_le_bytes());
+        records.extend_from_slice(&language.to_le_bytes());
+        records.extend_from_slice(&length.to_le_bytes());
+        records.extend_from_slice(&[difficulty, 0]);
+        records.extend_from_slice(&word.weight.to_le_bytes());
+        strings.extend_from_slice(word.text.as_bytes());
+    }
+    let mut bytes: Vec<u8> = Vec::with_capacity(HEADER_SIZE + names.len() * NAME_SIZE + groups.len() * GROUP_SIZE + records.len() + strings.len());
+    bytes.extend_from_slice(INDEX_MAGIC);
+    for count in [sorted.len(), names.len(), groups.len(), strings.len()] {
+        bytes.extend_from_slice(&(count as u32).to_le_bytes());
+    }
+    bytes.resize(HEADER_SIZE, 0);
+    for (offset, size) in names.iter() {
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&size.to_le_bytes());
+    }
+    for group in groups.iter() {
+        bytes.extend_from_slice(&group.language.to_le_bytes());
+        bytes.extend_from_slice(&group.category.to_le_bytes());
+        bytes.extend_from_slice(&group.length.to_le_bytes());
+        bytes.extend_from_slice(&[group.difficulty, 0]);
+        bytes.extend_from_slice(&group.start.to_le_bytes());
+        bytes.extend_from_slice(&group.count.to_le_bytes());
+    }
+    bytes.extend_from_slice(&records);
+    bytes.extend_from_slice(&strings);
+    bytes
+}
+
+/// A compiled word list read in place, from memory or from a memory-mapped file, without parsing every word.
+#[derive(Debug)]
+pub struct WordIndex<B: AsRef<[u8]>> {
+    bytes: B,
+    words: usize,
+    names: usize,
+    groups: usize,
+}
+
+impl<B: AsRef<[u8]>> WordIndex<B> {
+    /// Checks the header and the sizes of the sections, so reading the words later cannot go out of bounds.
+    pub fn new(bytes: B) -> Result<WordIndex<B>, Error> {
+        let invalid = |message: &str| Error::new(ErrorKind::InvalidData, format!("not a word index: {message}"));
+        let data = bytes.as_ref();
+        if data.len() < HEADER_SIZE || &data[..INDEX_MAGIC.len()] != INDEX_MAGIC {
+            return Err(invalid("unknown header"));
+        }
+        let [words, names, groups, strings] = [8, 12, 16, 20].map(|at| u32_at(data, at) as usize);
+        let expected = HEADER_SIZE + names * NAME_SIZE + groups * GROUP_SIZE + words * RECORD_SIZE + strings;
+        if data.len() != expected {
+            return Err(invalid(&format!("expected {expected} bytes, found {}", data.len())));
+        }
+        let index = WordIndex { bytes, words, names, groups };
+        from_utf8(index.strings()).map_err(|_| invalid("the words are not UTF-8"))?;
+        Ok(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    fn groups_start(&self) -> usize {
+        HEADER_SIZE + self.names * NAME_SIZE
+    }
+
+    fn records_start(&self) -> usize {
+        self.groups_start() + self.groups * GROUP_SIZE
+    }
+
+    fn strings(&self) -> &[u8] {
+        &self.bytes.as_ref()[self.records_start() + self.words * RECORD_SIZE..]
+    }
+
+    /// A slice of the string table, empty when a corrupt index points outside of it or inside a character.
+    fn string(&self, offset: u32, size: u32) -> &str {
+        let strings = self.strings();
+        strings.get(offset as usize..offset as usize + size as usize).and_then(|bytes| from_utf8(bytes).ok()).unwrap_or_default()
+    }
+
+    fn name(&self, id: u16) -> &str {
+        if id as usize >= self.names {
+            return "";
+        }
+        let at = HEADER_SIZE + id as usize * NAME_SIZE;
+        let bytes = self.bytes.as_ref();
+        self.string(u32_at(bytes, at), u32_at(bytes, at + 4))
+    }
+
+    fn group(&self, position: usize) -> Group {
+        let at = self.groups_start() + position * GROUP_SIZE;
+        let bytes = self.bytes.as_ref();
+        Group {
+            language: u16_at(bytes, at),
+            category: u16_at(bytes, at + 2),
+            length: u16_at(bytes, at + 4),
+            difficulty: bytes[at + 6],
+            start: u32_at(bytes, at + 8),
+            count: u32_at(bytes, at + 12),
+        }
+    }
+
+    pub fn text(&self, position: usize) -> &str {
+        let at = self.records_start() + position * RECORD_SIZE;
+        let bytes = self.bytes.as_ref();
+        self.string(u32_at(bytes, at), u32_at(bytes, at + 4))
+    }
+
+    pub fn weight(&self, position: usize) -> f64 {
+        f64_at(self.bytes.as_ref(), self.records_start() + position * RECORD_SIZE + 16)
+    }
+
+    pub fn word(&self, position: usize) -> Word {
+        let at = self.records_start() + position * RECORD_SIZE;
+        let bytes = self.bytes.as_ref();
+        let (category, language) = (self.name(u16_at(bytes, at + 8)), self.name(u16_at(bytes, at + 10)));
+        Word::new(self.text(position), category, language).with_weight(self.weight(position))
+    }
+
+    /// Positions of the words matching the filter and, when given, of that many letters, found by their groups.
+    pub fn query(&self, filter: &WordFilter, length: Option<usize>) -> Vec<usize> {
+        let same = |expected: &Option<String>, id: u16| expected.as_ref().is_none_or(|name| name.eq_ignore_ascii_case(self.name(id)));
+        (0..self.groups)
+            .map(|position| self.group(position))
+            .filter(|group| same(&filter.language, group.language) && same(&filter.category, group.category))
+            .filter(|group| filter.difficulty.is_none_or(|difficulty| difficulty_id(difficulty) == group.difficulty))
+            .filter(|group| length.is_none_or(|length| length == group.length as usize))
+            .flat_map(|group| {
+                let start = (group.start as usize).min(self.words);
+                start..(start + group.count as usize).min(self.words)
+            })
+            .collect()
+    }
+
+    pub fn words(&self, filter: &WordFilter, length: Option<usize>) -> Vec<Word> {
+        self.query(filter, length).into_iter().map(|position| self.word(position)).collect()
+    }
+
+    /// Draws a matching word like `choose_word`, reading only the weights of the candidates.
+    pub fn choose<R: Rng + ?Sized>(&self, filter: &WordFilter, rng: &mut R) -> Option<Word> {
+        let candidates = self.query(filter, None);
+        let first = candidates.first().map(|position| self.weight(*position));
+        let position = match candidates.iter().all(|position| Some(self.weight(*position)) == first) {
+            true => candidates.choose(rng),
+            false => candidates.choose_weighted(rng, |position| self.weight(*position)).ok(),
+        }?;
+        Some(self.word(*position))
+    }
+}
+
+/// Maps an index file into memory, so only the pages of the words looked up are read from the disk.
+#[cfg(feature = "index")]
+#[cfg(not(tarpaulin_include))]
+pub fn open_index(path: &Path) -> Result<WordIndex<Mmap>, Error> {
+    let file = File::open(path)?;
+    // SAFETY: the map is only read, and the index is checked before use; a file truncated by another process while
+    // the game runs is the same risk any memory-mapped reader takes.
+    let map = unsafe { Mmap::map(&file)? };
+    WordIndex::new(map)
+}
+
+pub fn is_index(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case(INDEX_EXTENSION))
+}
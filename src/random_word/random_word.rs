@@ -1,8 +1,9 @@
-use rand::seq::SliceRandom;
 use rand::thread_rng;
 
-pub fn get_random_word() -> &'static str {
-    let words = ["Apple", "World", "Dog", "Sky"];
-    let mut rng = thread_rng();
-    words.choose(&mut rng).unwrap()
+use crate::random_word::word_list::{built_in_words, choose_word, WordFilter};
+
+/// A word of any language from the built-in words.
+pub fn get_random_word() -> String {
+    let words = built_in_words();
+    choose_word(&words, &WordFilter::default(), &mut thread_rng()).map(|word| word.text.clone()).unwrap_or_default()
 }
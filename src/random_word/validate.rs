@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use crate::logger::logger::Logger;
+use crate::random_word::blocklist::Blocklist;
+use crate::random_word::word_list::{fold_accent, match_key, parse_weight, Weighting, DEFAULT_CATEGORY};
+
+pub const MIN_WORD_LENGTH: usize = 3;
+pub const MAX_WORD_LENGTH: usize = 20;
+
+pub const ENGLISH_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const PORTUGUESE_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzáàâãçéêíóôõú";
+
+/// Characters dropped from the suggested fix of a word, e.g. `ice-cream` becomes `icecream`.
+const SEPARATORS: [char; 5] = [' ', '-', '\'', '’', '.'];
+
+/// The lowercase letters words of `language` are written with, `None` for languages without a known alphabet.
+pub fn alphabet_of(language: &str) -> Option<&'static str> {
+    match language.to_lowercase().as_str() {
+        "en" => Some(ENGLISH_ALPHABET),
+        "pt" => Some(PORTUGUESE_ALPHABET),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationRules {
+    /// Lowercase letters the words may use, any letter when `None`.
+    pub alphabet: Option<String>,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub blocklist: Blocklist,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        ValidationRules { alphabet: None, min_length: MIN_WORD_LENGTH, max_length: MAX_WORD_LENGTH, blocklist: Blocklist::default() }
+    }
+}
+
+impl ValidationRules {
+    pub fn for_language(language: &str) -> ValidationRules {
+        ValidationRules { alphabet: alphabet_of(language).map(str::to_string), ..ValidationRules::default() }
+    }
+
+    pub fn allows(&self, letter: char) -> bool {
+        match &self.alphabet {
+            Some(alphabet) => letter.to_lowercase().all(|lower| alphabet.contains(lower)),
+            None => letter.is_alphabetic(),
+        }
+    }
+
+    /// The word with its accents folded and its separators dropped until it fits the alphabet, if that is enough.
+    pub fn fix(&self, word: &str) -> Option<String> {
+        let fixed: String = word.chars()
+            .filter(|letter| !SEPARATORS.contains(letter))
+            .map(|letter| if self.allows(letter) { Some(letter) } else { Some(fold_accent(letter)).filter(|folded| self.allows(*folded)) })
+            .collect::<Option<String>>()?;
+        (!fixed.is_empty()).then_some(fixed)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub line: usize,
+    pub word: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+impl Issue {
+    pub fn new(line: usize, word: &str, message: String) -> Issue {
+        Issue { line, word: word.to_string(), message, suggestion: None }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: String) -> Issue {
+        self.suggestion = Some(suggestion);
+        self
+    }
+}
+
+/// Checks every word of a list, taking words that only differ by case or accents for duplicates.
+pub fn validate_word_list(content: &str, rules: &ValidationRules) -> Vec<Issue> {
+    let remove = || "remove the line".to_string();
+    let mut issues: Vec<Issue> = Vec::new();
+    let mut seen: HashMap<String, (usize, String)> = HashMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+        if let Some(Err(message)) = Weighting::directive(line) {
+            issues.push(Issue::new(number, line, message).with_suggestion("write `# weighting: linear`, `log` or `uniform`".to_string()));
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut columns = line.split(',').map(|column| column.trim());
+        let word = columns.next().unwrap_or_default();
+        if word.is_empty() {
+            issues.push(Issue::new(number, word, "missing word".to_string()).with_suggestion(remove()));
+            continue;
+        }
+        let length = word.chars().count();
+        if let Some(invalid) = word.chars().find(|letter| !rules.allows(*letter)) {
+            let suggestion = rules.fix(word).map_or(remove(), |fixed| format!("replace with `{fixed}`"));
+            issues.push(Issue::new(number, word, format!("invalid character `{invalid}`")).with_suggestion(suggestion));
+        }
+        if length < rules.min_length {
+            issues.push(Issue::new(number, word, format!("too short ({length} < {})", rules.min_length)).with_suggestion(remove()));
+        }
+        if length > rules.max_length {
+            issues.push(Issue::new(number, word, format!("too long ({length} > {})", rules.max_length)).with_suggestion(remove()));
+        }
+        if rules.blocklist.contains(word) {
+            issues.push(Issue::new(number, word, "blocked word".to_string()).with_suggestion(remove()));
+        }
+        match seen.get(&match_key(word)) {
+            Some((first, text)) => issues.push(Issue::new(number, word, format!("duplicate of `{text}` on line {first}")).with_suggestion(remove())),
+            None => {
+                seen.insert(match_key(word), (number, word.to_string()));
+            }
+        }
+        let category = columns.next().filter(|category| !category.is_empty());
+        if category.is_none() {
+            issues.push(Issue::new(number, word, format!("missing category (defaults to {DEFAULT_CATEGORY})"))
+                .with_suggestion(format!("write `{word},{DEFAULT_CATEGORY}`")));
+        }
+        if let Some(weight) = columns.next().filter(|weight| parse_weight(weight).is_none()) {
+            let category = category.unwrap_or(DEFAULT_CATEGORY);
+            issues.push(Issue::new(number, word, format!("invalid weight `{weight}`")).with_suggestion(format!("write `{word},{category},1`")));
+        }
+    }
+    issues
+}
+
+pub fn print_issues(issues: &[Issue], logger: &mut dyn Logger) {
+    for issue in issues.iter() {
+        match &issue.suggestion {
+            Some(suggestion) => logger.print(format_args!("line {}: {}: {} - {}", issue.line, issue.word, issue.message, suggestion)),
+            None => logger.print(format_args!("line {}: {}: {}", issue.line, issue.word, issue.message)),
+        }
+    }
+    logger.print(format_args!("{} issue(s) found", issues.len()));
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::str::FromStr;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub const DEFAULT_CATEGORY: &str = "general";
+pub const DEFAULT_LANGUAGE: &str = "en";
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const DEFAULT_WEIGHT: f64 = 1.0;
+/// Comment line that sets how the weights of the following words are read, e.g. `# weighting: log`.
+pub const WEIGHTING_DIRECTIVE: &str = "weighting:";
+
+/// The default word packs compiled into the binary, by language.
+#[cfg(feature = "packs")]
+const EMBEDDED_PACKS: [(&str, &str); 2] = [
+    ("en", include_str!("../../assets/words/en.txt")),
+    ("pt", include_str!("../../assets/words/pt.txt")),
+];
+
+/// A few words of each language for slim builds without the `packs` feature.
+#[cfg(not(feature = "packs"))]
+const BUILT_IN_WORDS: [(&str, &str, &str); 16] = [
+    ("Apple", "fruit", "en"),
+    ("World", "general", "en"),
+    ("Dog", "animal", "en"),
+    ("Sky", "general", "en"),
+    ("Banana", "fruit", "en"),
+    ("Elephant", "animal", "en"),
+    ("Keyboard", "general", "en"),
+    ("Tiger", "animal", "en"),
+    ("Casa", "geral", "pt"),
+    ("Gato", "animal", "pt"),
+    ("Livro", "geral", "pt"),
+    ("Janela", "geral", "pt"),
+    ("Banana", "fruta", "pt"),
+    ("Cachorro", "animal", "pt"),
+    ("Abacaxi", "fruta", "pt"),
+    ("Borboleta", "animal", "pt"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn of(word: &str) -> Difficulty {
+        match word.chars().count() {
+            0..=4 => Difficulty::Easy,
+            5..=7 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!("unknown difficulty `{value}` (expected easy, medium or hard)")),
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Easy => "easy",
+                Self::Medium => "medium",
+                Self::Hard => "hard",
+            }
+        )
+    }
+}
+
+/// How the weight column of a word list turns into the chance of drawing each word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Weighting {
+    /// The weight as written, for weights picked by hand.
+    #[default]
+    Linear,
+    /// The logarithm of the weight, so word counts from a frequency list do not leave every other word out.
+    Log,
+    /// Every word as likely as the others.
+    Uniform,
+}
+
+impl Weighting {
+    pub fn apply(&self, weight: f64) -> f64 {
+        match self {
+            Weighting::Linear => weight,
+            Weighting::Log => weight.ln_1p(),
+            Weighting::Uniform => DEFAULT_WEIGHT,
+        }
+    }
+
+    /// The weighting set by a `# weighting: log` line, `None` for other lines.
+    pub fn directive(line: &str) -> Option<Result<Weighting, String>> {
+        let value = line.strip_prefix('#')?.trim().strip_prefix(WEIGHTING_DIRECTIVE)?;
+        Some(value.trim().parse())
+    }
+}
+
+impl FromStr for Weighting {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "linear" => Ok(Weighting::Linear),
+            "log" => Ok(Weighting::Log),
+            "uniform" => Ok(Weighting::Uniform),
+            _ => Err(format!("unknown weighting `{value}` (expected linear, log or uniform)")),
+        }
+    }
+}
+
+impl fmt::Display for Weighting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Linear => "linear",
+                Self::Log => "log",
+                Self::Uniform => "uniform",
+            }
+        )
+    }
+}
+
+/// A weight column, which must be a finite number not below zero.
+pub fn parse_weight(column: &str) -> Option<f64> {
+    column.parse::<f64>().ok().filter(|weight| weight.is_finite() && *weight >= 0.0)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    pub text: String,
+    pub category: String,
+    pub language: String,
+    /// How likely the word is to be drawn compared to the others.
+    pub weight: f64,
+}
+
+impl Word {
+    pub fn new(text: &str, category: &str, language: &str) -> Word {
+        Word { text: text.to_string(), category: category.to_string(), language: language.to_string(), weight: DEFAULT_WEIGHT }
+    }
+
+    pub fn with_weight(mut self, weight: f64) -> Word {
+        self.weight = weight;
+        self
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        Difficulty::of(&self.text)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordFilter {
+    pub category: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub language: Option<String>,
+}
+
+impl WordFilter {
+    pub fn matches(&self, word: &Word) -> bool {
+        self.category.as_ref().is_none_or(|category| category.eq_ignore_ascii_case(&word.category))
+            && self.difficulty.is_none_or(|difficulty| difficulty == word.difficulty())
+            && self.language.as_ref().is_none_or(|language| language.eq_ignore_ascii_case(&word.language))
+    }
+}
+
+/// The letter without its accent, e.g. `ã` becomes `a`, for comparisons where `avó` and `avo` are the same word.
+pub fn fold_accent(letter: char) -> char {
+    match letter {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'ç' => 'c',
+        'Ç' => 'C',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        'ý' | 'ÿ' => 'y',
+        'Ý' => 'Y',
+        _ => letter,
+    }
+}
+
+/// The word lowercased like the game does before comparing guesses, with the accents folded on top.
+pub fn match_key(text: &str) -> String {
+    text.trim().to_lowercase().chars().map(fold_accent).collect()
+}
+
+/// The words played when no word list is given, and the ones that are always there when the word lists go missing.
+#[cfg(feature = "packs")]
+pub fn built_in_words() -> Vec<Word> {
+    EMBEDDED_PACKS.iter().flat_map(|(language, content)| parse_word_list(content, language)).collect()
+}
+
+#[cfg(not(feature = "packs"))]
+pub fn built_in_words() -> Vec<Word> {
+    BUILT_IN_WORDS.iter().map(|(text, category, language)| Word::new(text, category, language)).collect()
+}
+
+/// `word,category,weight` lines where the category and the weight are optional, under `# weighting:` lines.
+pub fn parse_word_list(content: &str, language: &str) -> Vec<Word> {
+    let mut weighting = Weighting::default();
+    let mut words: Vec<Word> = Vec::new();
+    for line in content.lines().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+        if line.starts_with('#') {
+            if let Some(Ok(directive)) = Weighting::directive(line) {
+                weighting = directive;
+            }
+            continue;
+        }
+        let mut columns = line.split(',').map(|column| column.trim());
+        let text = columns.next().unwrap_or_default();
+        let category = columns.next().filter(|category| !category.is_empty()).unwrap_or(DEFAULT_CATEGORY);
+        let weight = columns.next().and_then(parse_weight).unwrap_or(DEFAULT_WEIGHT);
+        words.push(Word::new(text, category, language).with_weight(weighting.apply(weight)));
+    }
+    words
+}
+
+pub fn load_word_list(path: &Path, language: &str) -> Result<Vec<Word>, Error> {
+    let words = parse_word_list(&read_to_string(path)?, language);
+    if words.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidData, format!("{} has no words", path.display())));
+    }
+    Ok(words)
+}
+
+pub fn choose_word<'a, R: Rng + ?Sized>(words: &'a [Word], filter: &WordFilter, rng: &mut R) -> Option<&'a Word> {
+    let candidates: Vec<&Word> = words.iter().filter(|word| filter.matches(word)).collect();
+    // Lists without weights keep drawing the same words for the same seed.
+    if candidates.iter().all(|word| word.weight == candidates[0].weight) {
+        return candidates.choose(rng).copied();
+    }
+    candidates.choose_weighted(rng, |word| word.weight).ok().copied()
+}
+
+pub fn daily_seed(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
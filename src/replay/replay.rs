@@ -0,0 +1,247 @@
+#[cfg(feature = "terminal")]
+use std::io::stdin;
+#[cfg(feature = "terminal")]
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+#[cfg(feature = "terminal")]
+use std::thread;
+use std::time::Duration;
+
+#[cfg(feature = "terminal")]
+use crate::batch::batch::read_line;
+#[cfg(feature = "terminal")]
+use crate::clear_screen;
+use crate::game::game::{Game, GameStatus, HangmanGame, Snapshot};
+use crate::logger::event::{EventRecord, GameEvent};
+use crate::logger::logger::Logger;
+use crate::{print_gibbet_status, print_sequence, print_word_hits};
+
+pub const STEP_DELAY: Duration = Duration::from_millis(1000);
+pub const MIN_SPEED: f64 = 0.25;
+pub const MAX_SPEED: f64 = 8.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayStep {
+    pub caption: String,
+    pub snapshot: Snapshot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayCommand {
+    TogglePause,
+    Step,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayControls {
+    pub position: usize,
+    pub paused: bool,
+    pub speed: f64,
+}
+
+pub fn parse_event_log(content: &str) -> Result<Vec<EventRecord>, String> {
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| EventRecord::from_json(line).map_err(|error| format!("line {}: {error}", index + 1)))
+        .collect()
+}
+
+pub fn split_games(records: Vec<EventRecord>) -> Vec<Vec<EventRecord>> {
+    let mut games: Vec<Vec<EventRecord>> = Vec::new();
+    for record in records.into_iter() {
+        match (&record.event, games.last_mut()) {
+            (GameEvent::GameStarted { .. }, _) | (_, None) => games.push(vec![record]),
+            (_, Some(game)) => game.push(record),
+        }
+    }
+    games
+}
+
+pub fn describe_event(event: &GameEvent) -> String {
+    match event {
+        GameEvent::GameStarted { word, lives, attempts: 0 } => format!("Game started: {} letters, {lives} lives", word.chars().count()),
+        GameEvent::GameStarted { word, lives, attempts } => {
+            format!("Game started: {} letters, {} of {lives} lives left", word.chars().count(), lives.saturating_sub(*attempts))
+        }
+        GameEvent::LetterGuessed { letter, hit: true, .. } => format!("Guessed {}: hit", letter.to_uppercase()),
+        GameEvent::LetterGuessed { letter, hit: false, .. } => format!("Guessed {}: miss", letter.to_uppercase()),
+        GameEvent::WordGuessed { word, correct: true } => format!("Guessed the word {}", word.to_uppercase()),
+        GameEvent::WordGuessed { word, correct: false } => format!("Guessed the word {}: wrong", word.to_uppercase()),
+        GameEvent::HintUsed { letter, .. } => format!("Hint revealed {}", letter.to_uppercase()),
+        GameEvent::GuessTimedOut { .. } => "Time ran out: lost a life".to_string(),
+        GameEvent::GuessUndone { .. } => "Undid the last guess".to_string(),
+        GameEvent::GameWon { .. } => "Game won".to_string(),
+        GameEvent::GameLost { .. } => "Game lost".to_string(),
+    }
+}
+
+pub fn build_steps(records: &[EventRecord]) -> Result<(String, Vec<ReplayStep>), String> {
+    let Some(GameEvent::GameStarted { word, lives, attempts }) = records.first().map(|record| &record.event) else {
+        return Err("the log does not start with a game_started event".to_string());
+    };
+    let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), *attempts);
+    game.set_lives(*lives);
+    // Practice games may undo guesses, replaying any log as one lets them be rolled back too.
+    game.set_practice(true);
+    game.start();
+    let mut steps: Vec<ReplayStep> = vec![ReplayStep { caption: describe_event(&records[0].event), snapshot: game.snapshot() }];
+    for (index, record) in records.iter().enumerate().skip(1) {
+        let mismatch = |expected: String| format!("event {}: the log says {expected}, but the game disagrees", index + 1);
+        match &record.event {
+            GameEvent::GameStarted { .. } => return Err(format!("event {}: a second game starts here", index + 1)),
+            GameEvent::LetterGuessed { letter, hit, .. } => {
+                let attempts = game.attempts();
+                game.verify_answer(letter.clone());
+                if (game.attempts() == attempts) != *hit {
+                    return Err(mismatch(format!("{letter} was a {}", if *hit { "hit" } else { "miss" })));
+                }
+            }
+            GameEvent::WordGuessed { word, correct } => {
+                if game.guess_word(word.clone()) != *correct {
+                    return Err(mismatch(format!("{word} was {}", if *correct { "correct" } else { "wrong" })));
+                }
+            }
+            GameEvent::HintUsed { letter, .. } => {
+                if game.use_hint().as_ref() != Some(letter) {
+                    return Err(mismatch(format!("the hint revealed {letter}")));
+                }
+            }
+            GameEvent::GuessTimedOut { attempts } => {
+                game.time_out();
+                if game.attempts() != *attempts {
+                    return Err(mismatch(format!("{attempts} attempt(s) were used")));
+                }
+            }
+            GameEvent::GuessUndone { attempts } => {
+                if !game.undo() || game.attempts() != *attempts {
+                    return Err(mismatch(format!("a guess was undone back to {attempts} attempt(s)")));
+                }
+            }
+            GameEvent::GameWon { .. } if game.status() != GameStatus::Won => return Err(mismatch("the game was won".to_string())),
+            GameEvent::GameLost { .. } if game.status() == GameStatus::Won => return Err(mismatch("the game was lost".to_string())),
+            GameEvent::GameWon { .. } | GameEvent::GameLost { .. } => (),
+        }
+        steps.push(ReplayStep { caption: describe_event(&record.event), snapshot: game.snapshot() });
+    }
+    Ok((word.clone(), steps))
+}
+
+pub fn print_step(step: &ReplayStep, index: usize, total: usize, logger: &mut dyn Logger) {
+    logger.print(format_args!("\n\t\tJogo da Forca - replay {}/{total}", index + 1));
+    logger.print(format_args!("{}\n", step.caption));
+    print_sequence(" ".to_string(), step.snapshot.errors.clone(), logger);
+    print_gibbet_status(step.snapshot.stage(), logger);
+    print_word_hits(&step.snapshot.formed_word_by_hits, logger);
+}
+
+pub fn parse_command(input: &str) -> Option<ReplayCommand> {
+    match input.trim().to_lowercase().as_str() {
+        "" | "p" | "pause" | "play" => Some(ReplayCommand::TogglePause),
+        "n" | "s" | "step" | "next" => Some(ReplayCommand::Step),
+        "b" | "back" => Some(ReplayCommand::Back),
+        "+" | "faster" => Some(ReplayCommand::Faster),
+        "-" | "slower" => Some(ReplayCommand::Slower),
+        "q" | "quit" => Some(ReplayCommand::Quit),
+        _ => None,
+    }
+}
+
+impl ReplayControls {
+    /// A speed that is not a number plays at the normal speed, any other is kept within the limits.
+    pub fn new(paused: bool, speed: f64) -> ReplayControls {
+        let speed = if speed.is_finite() { speed.clamp(MIN_SPEED, MAX_SPEED) } else { 1.0 };
+        ReplayControls { position: 0, paused, speed }
+    }
+
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs_f64(STEP_DELAY.as_secs_f64() / self.speed)
+    }
+
+    pub fn apply(&mut self, command: ReplayCommand, total: usize) -> bool {
+        match command {
+            ReplayCommand::TogglePause => self.paused = !self.paused,
+            ReplayCommand::Step => {
+                self.paused = true;
+                self.position = (self.position + 1).min(total.saturating_sub(1));
+            }
+            ReplayCommand::Back => {
+                self.paused = true;
+                self.position = self.position.saturating_sub(1);
+            }
+            ReplayCommand::Faster => self.speed = (self.speed * 2.0).min(MAX_SPEED),
+            ReplayCommand::Slower => self.speed = (self.speed / 2.0).max(MIN_SPEED),
+            ReplayCommand::Quit => return false,
+        }
+        true
+    }
+
+    pub fn advance(&mut self, total: usize) -> bool {
+        if self.position + 1 >= total {
+            return false;
+        }
+        self.position += 1;
+        true
+    }
+}
+
+#[cfg(all(feature = "terminal", not(tarpaulin_include)))]
+fn spawn_command_reader() -> Receiver<Option<ReplayCommand>> {
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        while let Some(line) = read_line(stdin().lock()) {
+            if sender.send(parse_command(&line)).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(all(feature = "terminal", not(tarpaulin_include)))]
+pub fn run_replay(steps: &[ReplayStep], mut controls: ReplayControls, logger: &mut dyn Logger) {
+    let commands = spawn_command_reader();
+    let mut input_open = true;
+    loop {
+        clear_screen();
+        print_step(&steps[controls.position], controls.position, steps.len(), logger);
+        let state = if controls.paused { "paused" } else { "playing" };
+        logger.print(format_args!("\n[{state} x{}] Enter: play/pause  n: step  b: back  +/-: speed  q: quit", controls.speed));
+        let finished = controls.position + 1 >= steps.len();
+        let command = if controls.paused || (finished && input_open) {
+            match commands.recv() {
+                Ok(command) => command,
+                Err(_) if controls.paused && !finished => {
+                    input_open = false;
+                    controls.paused = false;
+                    None
+                }
+                Err(_) => return,
+            }
+        } else if finished {
+            return;
+        } else {
+            match commands.recv_timeout(controls.delay()) {
+                Ok(command) => command,
+                Err(RecvTimeoutError::Timeout) => {
+                    controls.advance(steps.len());
+                    None
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    input_open = false;
+                    thread::sleep(controls.delay());
+                    controls.advance(steps.len());
+                    None
+                }
+            }
+        };
+        if let Some(command) = command {
+            if !controls.apply(command, steps.len()) {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::io::{Error, Read};
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Response, Server};
+
+use crate::batch::batch::masked_word;
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::logger::logger::{Level, Logger};
+use crate::random_word::word_list::{choose_word, WordFilter};
+use crate::server::server::ServerOptions;
+
+pub const MAX_BODY: u64 = 16 * 1024;
+/// Most games kept at once by default, new games are refused until older ones expire.
+pub const MAX_GAMES: usize = 10_000;
+/// How long a finished game can still be looked up after its last guess.
+pub const FINISHED_TTL: Duration = Duration::from_secs(10 * 60);
+/// How long a game nobody guesses in is kept before it is dropped.
+pub const IDLE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// What a client may know about a game: the answer is only filled in once the game is over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameView {
+    pub id: u64,
+    pub word: String,
+    pub hits: Vec<String>,
+    pub misses: Vec<String>,
+    pub lives: u8,
+    pub lives_left: u8,
+    pub status: GameStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuessView {
+    pub result: String,
+    #[serde(flatten)]
+    pub game: GameView,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NewGameRequest {
+    pub lives: Option<u8>,
+    pub category: Option<String>,
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuessRequest {
+    pub letter: Option<String>,
+    pub word: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub struct Api {
+    options: ServerOptions,
+    rng: StdRng,
+    /// Every game with the time it was created or last guessed in.
+    games: HashMap<u64, (HangmanGame, Instant)>,
+    max_games: usize,
+    next_id: u64,
+}
+
+impl GameView {
+    pub fn of(id: u64, game: &HangmanGame) -> GameView {
+        let snapshot = game.snapshot();
+        let status = game.status();
+        GameView {
+            id,
+            word: masked_word(&snapshot.formed_word_by_hits),
+            hits: snapshot.hits,
+            misses: snapshot.errors,
+            lives: snapshot.lives,
+            lives_left: snapshot.lives.saturating_sub(snapshot.attempts),
+            status,
+            answer: (status != GameStatus::InProgress).then(|| game.word().to_lowercase()),
+        }
+    }
+}
+
+impl ApiResponse {
+    pub fn json<T: Serialize>(status: u16, value: &T) -> ApiResponse {
+        match serde_json::to_string(value) {
+            Ok(body) => ApiResponse { status, body },
+            Err(error) => ApiResponse::error(500, &error.to_string()),
+        }
+    }
+
+    pub fn error(status: u16, message: &str) -> ApiResponse {
+        ApiResponse { status, body: serde_json::json!({ "error": message }).to_string() }
+    }
+}
+
+fn parse_body<'a, T: Default + Deserialize<'a>>(body: &'a str) -> Result<T, ApiResponse> {
+    if body.trim().is_empty() {
+        return Ok(T::default());
+    }
+    serde_json::from_str(body).map_err(|error| ApiResponse::error(400, &format!("invalid request body: {error}")))
+}
+
+impl Api {
+    pub fn new(options: ServerOptions) -> Api {
+        let rng = match options.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Api { options, rng, games: HashMap::new(), max_games: MAX_GAMES, next_id: 1 }
+    }
+
+    pub fn with_max_games(mut self, max_games: usize) -> Api {
+        self.max_games = max_games;
+        self
+    }
+
+    pub fn handle(&mut self, method: &str, path: &str, body: &str) -> ApiResponse {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let result = match (method, segments.as_slice()) {
+            ("POST", ["games"]) => self.create(body),
+            ("GET", ["games", id]) => self.find(id).map(|(id, game)| ApiResponse::json(200, &GameView::of(id, game))),
+            ("POST", ["games", id, "guesses"]) => self.guess(id, body),
+            (_, ["games"] | ["games", _] | ["games", _, "guesses"]) => Err(ApiResponse::error(405, &format!("{method} is not allowed on {path}"))),
+            _ => Err(ApiResponse::error(404, &format!("{path} not found"))),
+        };
+        result.unwrap_or_else(|response| response)
+    }
+
+    fn find(&self, id: &str) -> Result<(u64, &HangmanGame), ApiResponse> {
+        let not_found = || ApiResponse::error(404, &format!("game {id} not found"));
+        let id: u64 = id.parse().map_err(|_| not_found())?;
+        self.games.get(&id).map(|(game, _)| (id, game)).ok_or_else(not_found)
+    }
+
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Drops the games finished more than `FINISHED_TTL` ago and the ones left alone for `IDLE_TTL`.
+    pub fn evict(&mut self, now: Instant) {
+        self.games.retain(|_, (game, touched)| {
+            let idle = now.saturating_duration_since(*touched);
+            idle < IDLE_TTL && (game.status() == GameStatus::InProgress || idle < FINISHED_TTL)
+        });
+    }
+
+    fn create(&mut self, body: &str) -> Result<ApiResponse, ApiResponse> {
+        let request: NewGameRequest = parse_body(body)?;
+        if request.lives == Some(0) {
+            return Err(ApiResponse::error(400, "lives must be at least 1"));
+        }
+        let now = Instant::now();
+        self.evict(now);
+        if self.games.len() >= self.max_games {
+            return Err(ApiResponse::error(503, "too many games in progress, try again later"));
+        }
+        let filter = WordFilter {
+            category: request.category.or(self.options.filter.category.clone()),
+            language: request.lang.or(self.options.filter.language.clone()),
+            ..self.options.filter.clone()
+        };
+        let word = choose_word(&self.options.words, &filter, &mut self.rng)
+            .ok_or(ApiResponse::error(422, "no word matches the given options"))?;
+        let game = HangmanGame::headless(&word.text, request.lives.unwrap_or(self.options.lives));
+        let id = self.next_id;
+        self.next_id += 1;
+        let response = ApiResponse::json(201, &GameView::of(id, &game));
+        self.games.insert(id, (game, now));
+        Ok(response)
+    }
+
+    fn guess(&mut self, id: &str, body: &str) -> Result<ApiResponse, ApiResponse> {
+        let request: GuessRequest = parse_body(body)?;
+        let (id, _) = self.find(id)?;
+        let (game, touched) = self.games.get_mut(&id).ok_or(ApiResponse::error(404, &format!("game {id} not found")))?;
+        *touched = Instant::now();
+        if game.status() != GameStatus::InProgress {
+            return Err(ApiResponse::error(409, "the game is over"));
+        }
+        let result = match (request.letter, request.word) {
+            (Some(letter), None) => {
+                let letter = letter.trim().to_lowercase();
+                if letter.chars().count() != 1 || !letter.chars().all(char::is_alphabetic) {
+                    return Err(ApiResponse::error(400, "letter must be a single letter"));
+                }
+                let snapshot = game.snapshot();
+                if snapshot.hits.contains(&letter) || snapshot.errors.contains(&letter) {
+                    return Err(ApiResponse::error(422, &format!("{letter} was already guessed")));
+                }
+                game.verify_answer(letter);
+                if game.attempts() == snapshot.attempts { "hit" } else { "miss" }
+            }
+            (None, Some(word)) if !word.trim().is_empty() => {
+                if game.guess_word(word.trim().to_string()) { "correct" } else { "wrong" }
+            }
+            _ => return Err(ApiResponse::error(400, "send either a letter or a word")),
+        };
+        Ok(ApiResponse::json(200, &GuessView { result: result.to_string(), game: GameView::of(id, game) }))
+    }
+}
+
+/// Answers requests one at a time until the server is shut down.
+#[cfg(not(tarpaulin_include))]
+pub fn serve_http(server: Server, api: &mut Api, logger: &mut dyn Logger) -> Result<(), Error> {
+    logger.print(format_args!("Listening on http://{}", server.server_addr()));
+    let content_type = Header::from_bytes("Content-Type", "application/json").map_err(|_| Error::other("invalid header"))?;
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let response = match request.as_reader().take(MAX_BODY).read_to_string(&mut body) {
+            Ok(_) => {
+                let path = request.url().split('?').next().unwrap_or_default().to_string();
+                api.handle(request.method().as_str(), &path, &body)
+            }
+            Err(error) => ApiResponse::error(400, &format!("could not read the request body: {error}")),
+        };
+        logger.log(Level::Debug, format_args!("{} {} -> {}", request.method(), request.url(), response.status));
+        let reply = Response::from_string(response.body).with_status_code(response.status).with_header(content_type.clone());
+        if let Err(error) = request.respond(reply) {
+            logger.log(Level::Warn, format_args!("could not answer a request: {error}"));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::random_word::word_list::choose_word;
+use crate::server::room::{Room, RoomMode};
+use crate::server::server::{parse_request, Request, ServerOptions, Session, GREETING};
+
+struct Client {
+    name: String,
+    session: Session,
+    room: Option<String>,
+    writer: Box<dyn Write + Send>,
+    /// Set once a write fails, the client is dropped before the lobby is unlocked.
+    stalled: bool,
+}
+
+/// Every connection of a server, the rooms they play in and where to send their replies.
+///
+/// Replies are written while the lobby is locked, so the writers must give up after a while (see `WRITE_TIMEOUT`) and a
+/// client that stops reading is dropped instead of holding up everyone else.
+pub struct Lobby {
+    options: ServerOptions,
+    rng: StdRng,
+    rooms: HashMap<String, Room>,
+    clients: HashMap<usize, Client>,
+}
+
+impl Lobby {
+    pub fn new(options: ServerOptions) -> Lobby {
+        let rng = match options.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Lobby { options, rng, rooms: HashMap::new(), clients: HashMap::new() }
+    }
+
+    pub fn rooms(&self) -> Vec<&Room> {
+        self.rooms.values().collect()
+    }
+
+    pub fn connect(&mut self, id: usize, writer: Box<dyn Write + Send>) {
+        // Seeded servers give every connection its own, still reproducible, sequence of words.
+        let options = ServerOptions { seed: self.options.seed.map(|seed| seed.wrapping_add(id as u64)), ..self.options.clone() };
+        let client = Client { name: format!("player{id}"), session: Session::new(options), room: None, writer, stalled: false };
+        self.clients.insert(id, client);
+        self.send(id, GREETING);
+    }
+
+    pub fn disconnect(&mut self, id: usize, now: Instant) {
+        let room = self.room_of(id);
+        let events = self.leave(id, now);
+        self.clients.remove(&id);
+        self.broadcast(room.as_deref(), &events);
+    }
+
+    /// Answers one line of a client and returns false once it asked to quit or was dropped.
+    pub fn dispatch(&mut self, id: usize, line: &str, now: Instant) -> bool {
+        if !self.clients.contains_key(&id) {
+            return false;
+        }
+        if line.trim().is_empty() {
+            return true;
+        }
+        let request = parse_request(line);
+        let quit = request == Ok(Request::Quit);
+        let (reply, events) = match request.and_then(|request| self.handle(id, request, now)) {
+            Ok((reply, events)) => (format!("OK {reply}"), events),
+            Err(error) => (format!("ERR {error}"), Vec::new()),
+        };
+        self.send(id, &reply);
+        self.broadcast(self.room_of(id).as_deref(), &events);
+        self.drop_stalled(now);
+        !quit && self.clients.contains_key(&id)
+    }
+
+    pub fn tick(&mut self, now: Instant) {
+        let ticks: Vec<(String, Vec<String>)> = self.rooms.iter_mut().map(|(name, room)| (name.clone(), room.tick(now))).collect();
+        for (name, events) in ticks.into_iter() {
+            self.broadcast(Some(&name), &events);
+        }
+        self.drop_stalled(now);
+    }
+
+    /// Disconnects the clients a write failed for, which may in turn fail writing their leave events to others.
+    fn drop_stalled(&mut self, now: Instant) {
+        while let Some(id) = self.clients.iter().find(|(_, client)| client.stalled).map(|(id, _)| *id) {
+            self.disconnect(id, now);
+        }
+    }
+
+    fn room_of(&self, id: usize) -> Option<String> {
+        self.clients.get(&id).and_then(|client| client.room.clone())
+    }
+
+    fn handle(&mut self, id: usize, request: Request, now: Instant) -> Result<(String, Vec<String>), String> {
+        let room = self.room_of(id);
+        match (request, room) {
+            (Request::Name(name), None) => {
+                if let Some(client) = self.clients.get_mut(&id) {
+                    client.name = name.clone();
+                }
+                Ok((format!("name {name}"), Vec::new()))
+            }
+            (Request::Name(_), Some(_)) => Err("send LEAVE before changing your name".to_string()),
+            (Request::Join(room, _), Some(current)) => Err(format!("already in room {current}, send LEAVE before joining {room}")),
+            (Request::Join(room, mode), None) => self.join(id, &room, mode, now),
+            (Request::Leave, None) => Err("not in a room".to_string()),
+            (Request::Leave, Some(room)) => {
+                let events = self.leave(id, now);
+                self.broadcast(Some(&room), &events);
+                Ok((format!("left {room}"), Vec::new()))
+            }
+            (Request::Quit, Some(room)) => {
+                let events = self.leave(id, now);
+                self.broadcast(Some(&room), &events);
+                Ok(("bye".to_string(), Vec::new()))
+            }
+            (Request::State, Some(room)) => Ok((self.rooms[&room].state_of(id), Vec::new())),
+            (Request::New, Some(room)) => {
+                let word = choose_word(&self.options.words, &self.options.filter, &mut self.rng)
+                    .ok_or("no word matches the server options".to_string())?
+                    .text
+                    .clone();
+                let room = self.rooms.get_mut(&room).ok_or("the room was closed".to_string())?;
+                let events = room.restart(&word, now)?;
+                Ok((room.state_of(id), events))
+            }
+            (request @ (Request::Guess(_) | Request::Word(_)), Some(room)) => {
+                self.rooms.get_mut(&room).ok_or("the room was closed".to_string())?.play(id, request, now)
+            }
+            (request, None) => {
+                let client = self.clients.get_mut(&id).ok_or("unknown client".to_string())?;
+                client.session.handle(request).map(|reply| (reply, Vec::new()))
+            }
+        }
+    }
+
+    fn join(&mut self, id: usize, name: &str, mode: Option<RoomMode>, now: Instant) -> Result<(String, Vec<String>), String> {
+        if !self.rooms.contains_key(name) {
+            let word = choose_word(&self.options.words, &self.options.filter, &mut self.rng)
+                .ok_or("no word matches the server options".to_string())?;
+            let room = Room::new(name, mode.unwrap_or(RoomMode::Cooperative), &word.text, self.options.lives, self.options.turn_timeout, now);
+            self.rooms.insert(name.to_string(), room);
+        }
+        let room = self.rooms.get_mut(name).ok_or("the room was closed".to_string())?;
+        if mode.is_some_and(|mode| mode != room.mode()) {
+            return Err(format!("room {name} is a {} room", room.mode()));
+        }
+        let player = self.clients.get(&id).map(|client| client.name.clone()).unwrap_or_default();
+        let events = room.join(id, &player, now)?;
+        let reply = format!("joined {name} {} {}", room.mode(), room.state_of(id));
+        if let Some(client) = self.clients.get_mut(&id) {
+            client.room = Some(name.to_string());
+        }
+        Ok((reply, events))
+    }
+
+    fn leave(&mut self, id: usize, now: Instant) -> Vec<String> {
+        let Some(name) = self.clients.get_mut(&id).and_then(|client| client.room.take()) else {
+            return Vec::new();
+        };
+        let Some(room) = self.rooms.get_mut(&name) else {
+            return Vec::new();
+        };
+        let events = room.leave(id, now);
+        if room.is_empty() {
+            self.rooms.remove(&name);
+        }
+        events
+    }
+
+    fn send(&mut self, id: usize, line: &str) {
+        if let Some(client) = self.clients.get_mut(&id).filter(|client| !client.stalled) {
+            // The connection is closing or the client stopped reading, either way nothing more is sent to it.
+            client.stalled = writeln!(client.writer, "{line}").and_then(|_| client.writer.flush()).is_err();
+        }
+    }
+
+    fn broadcast(&mut self, room: Option<&str>, events: &[String]) {
+        let Some(members) = room.and_then(|room| self.rooms.get(room)).map(|room| room.members()) else {
+            return;
+        };
+        for event in events.iter() {
+            for id in members.iter() {
+                self.send(*id, event);
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::server::server::{describe, Request};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomMode {
+    Cooperative,
+    Competitive,
+}
+
+impl FromStr for RoomMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "coop" | "cooperative" => Ok(RoomMode::Cooperative),
+            "versus" | "competitive" => Ok(RoomMode::Competitive),
+            _ => Err(format!("unknown room mode `{value}` (expected coop or versus)")),
+        }
+    }
+}
+
+impl fmt::Display for RoomMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomMode::Cooperative => write!(f, "coop"),
+            RoomMode::Competitive => write!(f, "versus"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Player {
+    pub id: usize,
+    pub name: String,
+    game: HangmanGame,
+}
+
+/// Several players guessing the same word, one at a time in the order they joined.
+#[derive(Debug)]
+pub struct Room {
+    name: String,
+    mode: RoomMode,
+    word: String,
+    lives: u8,
+    turn_timeout: Duration,
+    shared: HangmanGame,
+    players: Vec<Player>,
+    turn: usize,
+    turn_started: Instant,
+    winner: Option<String>,
+}
+
+impl Room {
+    pub fn new(name: &str, mode: RoomMode, word: &str, lives: u8, turn_timeout: Duration, now: Instant) -> Room {
+        Room {
+            name: name.to_string(),
+            mode,
+            word: word.to_string(),
+            lives,
+            turn_timeout,
+            shared: HangmanGame::headless(word, lives),
+            players: Vec::new(),
+            turn: 0,
+            turn_started: now,
+            winner: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn mode(&self) -> RoomMode {
+        self.mode
+    }
+
+    pub fn members(&self) -> Vec<usize> {
+        self.players.iter().map(|player| player.id).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&Player> {
+        self.players.get(self.turn)
+    }
+
+    fn game_of(&self, id: usize) -> Option<&HangmanGame> {
+        match self.mode {
+            RoomMode::Cooperative => Some(&self.shared),
+            RoomMode::Competitive => self.players.iter().find(|player| player.id == id).map(|player| &player.game),
+        }
+    }
+
+    fn is_playing(&self, player: &Player) -> bool {
+        match self.mode {
+            RoomMode::Cooperative => true,
+            RoomMode::Competitive => player.game.status() == GameStatus::InProgress,
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        match self.mode {
+            RoomMode::Cooperative => self.shared.status() != GameStatus::InProgress,
+            RoomMode::Competitive => self.winner.is_some() || (!self.players.is_empty() && !self.players.iter().any(|player| self.is_playing(player))),
+        }
+    }
+
+    pub fn state_of(&self, id: usize) -> String {
+        match (self.mode, &self.winner) {
+            (RoomMode::Competitive, Some(winner)) => format!("result {} word={}", if self.name_of(id) == Some(winner) { "won" } else { "lost" }, self.word.to_lowercase()),
+            _ => self.game_of(id).map(describe).unwrap_or_default(),
+        }
+    }
+
+    fn name_of(&self, id: usize) -> Option<&String> {
+        self.players.iter().find(|player| player.id == id).map(|player| &player.name)
+    }
+
+    fn turn_event(&self) -> Vec<String> {
+        match self.current() {
+            Some(player) if !self.is_over() => vec![format!("EVENT turn {}", player.name)],
+            _ => Vec::new(),
+        }
+    }
+
+    fn advance_turn(&mut self, now: Instant) {
+        self.turn_started = now;
+        for _ in 0..self.players.len() {
+            self.turn = (self.turn + 1) % self.players.len();
+            if self.is_playing(&self.players[self.turn]) {
+                return;
+            }
+        }
+    }
+
+    pub fn join(&mut self, id: usize, name: &str, now: Instant) -> Result<Vec<String>, String> {
+        if self.players.iter().any(|player| player.name == name) {
+            return Err(format!("the name {name} is already taken in room {}", self.name));
+        }
+        if self.players.is_empty() {
+            self.turn = 0;
+            self.turn_started = now;
+        }
+        self.players.push(Player { id, name: name.to_string(), game: HangmanGame::headless(&self.word, self.lives) });
+        let mut events = vec![format!("EVENT joined {name} players={}", self.players.len())];
+        if self.players.len() == 1 {
+            events.extend(self.turn_event());
+        }
+        Ok(events)
+    }
+
+    pub fn leave(&mut self, id: usize, now: Instant) -> Vec<String> {
+        let Some(index) = self.players.iter().position(|player| player.id == id) else {
+            return Vec::new();
+        };
+        let player = self.players.remove(index);
+        let mut events = vec![format!("EVENT left {}", player.name)];
+        if self.players.is_empty() {
+            return events;
+        }
+        if index < self.turn {
+            self.turn -= 1;
+        } else if index == self.turn {
+            self.turn = (self.turn + self.players.len() - 1) % self.players.len();
+            self.advance_turn(now);
+            events.extend(self.turn_event());
+        }
+        events
+    }
+
+    pub fn play(&mut self, id: usize, request: Request, now: Instant) -> Result<(String, Vec<String>), String> {
+        if self.is_over() {
+            return Err("the game is over, send NEW to play again".to_string());
+        }
+        let current = self.current().ok_or("the room is empty".to_string())?;
+        if current.id != id {
+            return Err(format!("it is the turn of {}", current.name));
+        }
+        let name = current.name.clone();
+        let game = match self.mode {
+            RoomMode::Cooperative => &mut self.shared,
+            RoomMode::Competitive => &mut self.players[self.turn].game,
+        };
+        let (reply, guess) = match request {
+            Request::Guess(letter) => {
+                let snapshot = game.snapshot();
+                if snapshot.hits.contains(&letter) || snapshot.errors.contains(&letter) {
+                    return Err(format!("already guessed {letter}"));
+                }
+                game.verify_answer(letter.clone());
+                let result = if game.attempts() == snapshot.attempts { "hit" } else { "miss" };
+                (format!("{result} {}", describe(game)), format!("EVENT guess {name} {letter} {result}"))
+            }
+            Request::Word(word) => {
+                let result = if game.guess_word(word.clone()) { "correct" } else { "wrong" };
+                (format!("{result} {}", describe(game)), format!("EVENT word {name} {word} {result}"))
+            }
+            _ => return Err("only GUESS and WORD are played in turns".to_string()),
+        };
+        let status = game.status();
+        let mut events = match self.mode {
+            RoomMode::Cooperative => vec![format!("{guess} {}", describe(game))],
+            RoomMode::Competitive => vec![guess],
+        };
+        match (self.mode, status) {
+            (RoomMode::Cooperative, GameStatus::Won | GameStatus::Lost) => (),
+            (RoomMode::Competitive, GameStatus::Won) => {
+                events.push(format!("EVENT winner {name} word={}", self.word.to_lowercase()));
+                self.winner = Some(name);
+            }
+            (RoomMode::Competitive, GameStatus::Lost) => {
+                events.push(format!("EVENT out {name}"));
+                if self.is_over() {
+                    events.push(format!("EVENT result lost word={}", self.word.to_lowercase()));
+                }
+            }
+            (_, GameStatus::InProgress) => (),
+        }
+        if !self.is_over() {
+            self.advance_turn(now);
+            events.extend(self.turn_event());
+        }
+        Ok((reply, events))
+    }
+
+    /// Skips the turn of a player who did not guess within the turn timeout.
+    pub fn tick(&mut self, now: Instant) -> Vec<String> {
+        if self.is_over() || now.duration_since(self.turn_started) < self.turn_timeout {
+            return Vec::new();
+        }
+        let Some(player) = self.current() else {
+            return Vec::new();
+        };
+        let mut events = vec![format!("EVENT timeout {}", player.name)];
+        self.advance_turn(now);
+        events.extend(self.turn_event());
+        events
+    }
+
+    pub fn restart(&mut self, word: &str, now: Instant) -> Result<Vec<String>, String> {
+        if !self.is_over() {
+            return Err("the game of this room is not over yet".to_string());
+        }
+        self.word = word.to_string();
+        self.shared = HangmanGame::headless(word, self.lives);
+        for player in self.players.iter_mut() {
+            player.game = HangmanGame::headless(word, self.lives);
+        }
+        self.winner = None;
+        self.turn = 0;
+        self.turn_started = now;
+        let mut events = vec![format!("EVENT new {}", describe(&self.shared))];
+        events.extend(self.turn_event());
+        Ok(events)
+    }
+}
@@ -0,0 +1,214 @@
+//! Line protocol spoken by `jogo_da_forca serve`.
+//!
+//! Every request is one line and every reply is one line starting with `OK` or `ERR`:
+//!
+//! - `NEW` starts a game: `OK state word=_____ misses= lives=6/6`
+//! - `GUESS x` guesses a letter: `OK hit state ...`, `OK miss state ...` or `OK hit result won word=apple`
+//! - `WORD w` guesses the whole word: `OK correct result won word=apple` or `OK wrong state ...`
+//! - `STATE` shows the game: `OK state ...` or `OK result lost word=apple`
+//! - `QUIT` closes the connection: `OK bye`
+//! - `NAME n` sets the name shown to the other players: `OK name n`
+//! - `JOIN room [coop|versus]` joins or creates a room: `OK joined room coop state ...`
+//! - `LEAVE` goes back to playing alone: `OK left room`
+//!
+//! Inside a room players guess in the order they joined, and everyone receives `EVENT ...` lines
+//! such as `EVENT guess ana a hit`, `EVENT turn bia`, `EVENT timeout bia` or `EVENT winner ana word=apple`.
+use std::io::{BufRead, BufReader, Error};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::batch::batch::format_state;
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::logger::logger::{Level, Logger};
+use crate::random_word::word_list::{choose_word, Word, WordFilter};
+use crate::server::lobby::Lobby;
+use crate::server::room::RoomMode;
+
+pub const GREETING: &str = "OK jogo_da_forca ready, send NEW to start a game";
+pub const DEFAULT_TURN_TIMEOUT: Duration = Duration::from_secs(60);
+pub const TICK: Duration = Duration::from_millis(250);
+/// How long a reply may wait for a client to read, after that the client is dropped.
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Request {
+    New,
+    Guess(String),
+    Word(String),
+    State,
+    Quit,
+    Name(String),
+    Join(String, Option<RoomMode>),
+    Leave,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerOptions {
+    pub words: Arc<Vec<Word>>,
+    pub filter: WordFilter,
+    pub lives: u8,
+    pub seed: Option<u64>,
+    pub turn_timeout: Duration,
+}
+
+#[derive(Debug)]
+pub struct Session {
+    options: ServerOptions,
+    rng: StdRng,
+    game: Option<HangmanGame>,
+}
+
+pub fn parse_request(line: &str) -> Result<Request, String> {
+    let line = line.trim();
+    let (command, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let argument = argument.trim();
+    match (command.to_uppercase().as_str(), argument.is_empty()) {
+        ("NEW", true) => Ok(Request::New),
+        ("STATE", true) => Ok(Request::State),
+        ("QUIT", true) => Ok(Request::Quit),
+        ("LEAVE", true) => Ok(Request::Leave),
+        ("NAME", false) if !argument.contains(char::is_whitespace) => Ok(Request::Name(argument.to_string())),
+        ("NAME", _) => Err("NAME takes a single word".to_string()),
+        ("JOIN", false) => {
+            let mut parts = argument.split_whitespace();
+            let room = parts.next().unwrap_or_default().to_string();
+            let mode = parts.next().map(|mode| mode.parse()).transpose()?;
+            if parts.next().is_some() {
+                return Err("JOIN takes a room and optionally coop or versus".to_string());
+            }
+            Ok(Request::Join(room, mode))
+        }
+        ("JOIN", true) => Err("JOIN takes the name of a room".to_string()),
+        ("GUESS", false) if argument.chars().count() == 1 && argument.chars().all(char::is_alphabetic) => Ok(Request::Guess(argument.to_lowercase())),
+        ("GUESS", _) => Err("GUESS takes a single letter".to_string()),
+        ("WORD", false) => Ok(Request::Word(argument.to_lowercase())),
+        ("WORD", true) => Err("WORD takes the word you are guessing".to_string()),
+        ("NEW" | "STATE" | "QUIT" | "LEAVE", false) => Err(format!("{} takes no argument", command.to_uppercase())),
+        _ => Err(format!("unknown command `{command}`, expected NEW, GUESS, WORD, STATE, QUIT, NAME, JOIN or LEAVE")),
+    }
+}
+
+pub fn describe(game: &HangmanGame) -> String {
+    let snapshot = game.snapshot();
+    match game.status() {
+        GameStatus::Won => format!("result won word={}", game.word().to_lowercase()),
+        GameStatus::Lost => format!("result lost word={}", game.word().to_lowercase()),
+        GameStatus::InProgress => format_state(&snapshot.formed_word_by_hits, &snapshot.errors, snapshot.attempts, snapshot.lives),
+    }
+}
+
+impl Session {
+    pub fn new(options: ServerOptions) -> Session {
+        let rng = match options.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Session { options, rng, game: None }
+    }
+
+    pub fn game(&self) -> Option<&HangmanGame> {
+        self.game.as_ref()
+    }
+
+    fn new_game(&mut self) -> Result<String, String> {
+        let word = choose_word(&self.options.words, &self.options.filter, &mut self.rng)
+            .ok_or("no word matches the server options".to_string())?;
+        let game = HangmanGame::headless(&word.text, self.options.lives);
+        let reply = describe(&game);
+        self.game = Some(game);
+        Ok(reply)
+    }
+
+    fn playing(&mut self) -> Result<&mut HangmanGame, String> {
+        match self.game.as_mut() {
+            None => Err("no game in progress, send NEW first".to_string()),
+            Some(game) if game.status() != GameStatus::InProgress => Err("the game is over, send NEW to play again".to_string()),
+            Some(game) => Ok(game),
+        }
+    }
+
+    pub fn handle(&mut self, request: Request) -> Result<String, String> {
+        match request {
+            Request::New => self.new_game(),
+            Request::State => self.game.as_ref().map(describe).ok_or("no game in progress, send NEW first".to_string()),
+            Request::Quit => Ok("bye".to_string()),
+            Request::Name(_) | Request::Join(..) | Request::Leave => Err("rooms are handled by the lobby".to_string()),
+            Request::Guess(letter) => {
+                let game = self.playing()?;
+                let snapshot = game.snapshot();
+                if snapshot.hits.contains(&letter) || snapshot.errors.contains(&letter) {
+                    return Err(format!("already guessed {letter}"));
+                }
+                game.verify_answer(letter);
+                let result = if game.attempts() == snapshot.attempts { "hit" } else { "miss" };
+                Ok(format!("{result} {}", describe(game)))
+            }
+            Request::Word(word) => {
+                let game = self.playing()?;
+                let result = if game.guess_word(word) { "correct" } else { "wrong" };
+                Ok(format!("{result} {}", describe(game)))
+            }
+        }
+    }
+
+    pub fn reply(&mut self, line: &str) -> String {
+        match parse_request(line).and_then(|request| self.handle(request)) {
+            Ok(reply) => format!("OK {reply}"),
+            Err(error) => format!("ERR {error}"),
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn lock(lobby: &Mutex<Lobby>) -> MutexGuard<'_, Lobby> {
+    lobby.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(not(tarpaulin_include))]
+fn handle_stream(id: usize, stream: TcpStream, lobby: Arc<Mutex<Lobby>>) -> Result<(), Error> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    lock(&lobby).connect(id, Box::new(stream.try_clone()?));
+    let mut result = Ok(());
+    for line in BufReader::new(stream).lines() {
+        // A broken connection still leaves its room, or a versus room would wait for its turn forever.
+        match line {
+            Ok(line) if lock(&lobby).dispatch(id, &line, Instant::now()) => (),
+            Ok(_) => break,
+            Err(error) => {
+                result = Err(error);
+                break;
+            }
+        }
+    }
+    lock(&lobby).disconnect(id, Instant::now());
+    result
+}
+
+/// Accepts connections until the listener fails, giving each of them its own game until it joins a room.
+#[cfg(not(tarpaulin_include))]
+pub fn serve(listener: TcpListener, options: ServerOptions, logger: &mut dyn Logger) -> Result<(), Error> {
+    logger.print(format_args!("Listening on {}", listener.local_addr()?));
+    let lobby = Arc::new(Mutex::new(Lobby::new(options)));
+    let ticker = Arc::clone(&lobby);
+    thread::spawn(move || loop {
+        thread::sleep(TICK);
+        lock(&ticker).tick(Instant::now());
+    });
+    for (index, stream) in listener.incoming().enumerate() {
+        let stream = stream?;
+        let peer = stream.peer_addr()?;
+        logger.log(Level::Debug, format_args!("connection {} from {peer}", index + 1));
+        let lobby = Arc::clone(&lobby);
+        thread::spawn(move || {
+            if let Err(error) = handle_stream(index + 1, stream, lobby) {
+                eprintln!("warning: connection from {peer} failed: {error}");
+            }
+        });
+    }
+    Ok(())
+}
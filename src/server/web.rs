@@ -0,0 +1,102 @@
+use std::io::{Error, Read, Write};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Request, Response, Server};
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+use crate::logger::logger::{Level, Logger};
+use crate::puppet::gibbet::Gibbet;
+use crate::server::http::GameView;
+use crate::server::server::{parse_request, Request as LineRequest, ServerOptions, Session, GREETING};
+
+pub const INDEX_HTML: &str = include_str!("../../assets/web/index.html");
+pub const SOCKET_PATH: &str = "/ws";
+
+/// Sent to the browser after every line it plays, with the gibbet already drawn.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebUpdate {
+    pub reply: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game: Option<GameView>,
+    pub stage: u8,
+    pub gibbet: String,
+}
+
+impl WebUpdate {
+    pub fn of(id: u64, session: &Session, reply: String) -> WebUpdate {
+        let game = session.game().map(|game| GameView::of(id, game));
+        let stage = session.game().map_or(0, |game| game.stage());
+        let gibbet = Gibbet::from_attempts(stage).map(|gibbet| gibbet.to_string()).unwrap_or_default();
+        WebUpdate { reply, game, stage, gibbet }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Plays the line protocol over text messages until the browser closes the socket or sends QUIT.
+pub fn handle_socket<S: Read + Write>(socket: &mut WebSocket<S>, session: &mut Session, id: u64) -> Result<(), Error> {
+    socket.send(Message::text(WebUpdate::of(id, session, GREETING.to_string()).to_json())).map_err(Error::other)?;
+    loop {
+        let line = match socket.read().map_err(Error::other)? {
+            Message::Text(text) => text.to_string(),
+            Message::Close(_) => return Ok(()),
+            _ => continue,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = session.reply(&line);
+        socket.send(Message::text(WebUpdate::of(id, session, reply).to_json())).map_err(Error::other)?;
+        if parse_request(&line) == Ok(LineRequest::Quit) {
+            return socket.close(None).map_err(Error::other);
+        }
+    }
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name, value).expect("header names and values are ASCII")
+}
+
+fn header_of<'a>(request: &'a Request, name: &'static str) -> Option<&'a str> {
+    request.headers().iter().find(|header| header.field.equiv(name)).map(|header| header.value.as_str())
+}
+
+#[cfg(not(tarpaulin_include))]
+fn upgrade(request: Request, id: u64, options: ServerOptions) -> Result<(), Error> {
+    let Some(key) = header_of(&request, "Sec-WebSocket-Key").map(|key| derive_accept_key(key.as_bytes())) else {
+        return request.respond(Response::from_string("expected a WebSocket handshake").with_status_code(400));
+    };
+    let response = Response::empty(101).with_header(header("Sec-WebSocket-Accept", &key));
+    let stream = request.upgrade("websocket", response);
+    thread::spawn(move || {
+        let mut socket = WebSocket::from_raw_socket(stream, Role::Server, None);
+        let options = ServerOptions { seed: options.seed.map(|seed| seed.wrapping_add(id)), ..options };
+        // Browsers closing the tab drop the connection without a close frame, there is nothing to report.
+        let _ = handle_socket(&mut socket, &mut Session::new(options), id);
+    });
+    Ok(())
+}
+
+/// Serves the browser game at `/` and its WebSocket at `/ws`, one game per socket.
+#[cfg(not(tarpaulin_include))]
+pub fn serve_web(server: Server, options: ServerOptions, logger: &mut dyn Logger) -> Result<(), Error> {
+    logger.print(format_args!("Open http://{} in a browser to play", server.server_addr()));
+    for (index, request) in server.incoming_requests().enumerate() {
+        logger.log(Level::Debug, format_args!("{} {}", request.method(), request.url()));
+        let path = request.url().split('?').next().unwrap_or_default().to_string();
+        let result = match path.as_str() {
+            "/" | "/index.html" => request.respond(Response::from_string(INDEX_HTML).with_header(header("Content-Type", "text/html; charset=utf-8"))),
+            SOCKET_PATH => upgrade(request, index as u64 + 1, options.clone()),
+            _ => request.respond(Response::from_string("not found").with_status_code(404)),
+        };
+        if let Err(error) = result {
+            logger.log(Level::Warn, format_args!("could not answer a request: {error}"));
+        }
+    }
+    Ok(())
+}
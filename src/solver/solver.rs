@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::logger::logger::Logger;
+use crate::random_word::word_list::Word;
+
+pub const UNKNOWN: char = '_';
+
+pub fn matches_pattern(word: &str, pattern: &str, misses: &str) -> bool {
+    let word: Vec<char> = word.to_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let misses: String = misses.to_lowercase();
+    if word.len() != pattern.len() {
+        return false;
+    }
+    let revealed: BTreeSet<char> = pattern.iter().filter(|c| **c != UNKNOWN).copied().collect();
+    word.iter().zip(pattern.iter()).all(|(letter, known)| {
+        if *known == UNKNOWN {
+            !revealed.contains(letter) && !misses.contains(*letter)
+        } else {
+            letter == known
+        }
+    })
+}
+
+pub fn candidates<'a>(words: &'a [Word], pattern: &str, misses: &str) -> Vec<&'a Word> {
+    let mut seen: BTreeSet<String> = BTreeSet::new();
+    words.iter()
+        .filter(|word| matches_pattern(&word.text, pattern, misses))
+        .filter(|word| seen.insert(word.text.to_lowercase()))
+        .collect()
+}
+
+pub fn suggest_letter(candidates: &[&Word], pattern: &str, misses: &str) -> Option<char> {
+    let guessed: String = format!("{}{}", pattern, misses).to_lowercase();
+    let mut frequencies: BTreeMap<char, usize> = BTreeMap::new();
+    for word in candidates.iter() {
+        let letters: BTreeSet<char> = word.text.to_lowercase().chars().filter(|c| !guessed.contains(*c)).collect();
+        for letter in letters {
+            *frequencies.entry(letter).or_default() += 1;
+        }
+    }
+    frequencies.into_iter().max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a))).map(|(letter, _)| letter)
+}
+
+pub fn print_solution(words: &[Word], pattern: &str, misses: &str, logger: &mut dyn Logger) {
+    let found: Vec<&Word> = candidates(words, pattern, misses);
+    logger.print(format_args!("{} candidate(s)", found.len()));
+    for word in found.iter() {
+        logger.print(format_args!("{}", word.text.to_lowercase()));
+    }
+    if let Some(letter) = suggest_letter(&found, pattern, misses) {
+        logger.print(format_args!("Suggested letter: {letter}"));
+    }
+}
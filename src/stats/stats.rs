@@ -0,0 +1,138 @@
+use std::env;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::logger::logger::Logger;
+
+pub const STATS_FILE: &str = "stats.csv";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord {
+    pub timestamp: u64,
+    pub word: String,
+    pub won: bool,
+    pub attempts: u8,
+    pub lives: u8,
+    /// Guesses taken back in practice mode, such games are left out of the summary.
+    pub undos: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Summary {
+    pub played: usize,
+    pub won: usize,
+    pub lost: usize,
+    pub current_streak: usize,
+    pub best_streak: usize,
+    pub practice: usize,
+}
+
+impl GameRecord {
+    pub fn new(word: &str, won: bool, attempts: u8, lives: u8) -> GameRecord {
+        GameRecord { timestamp: now(), word: word.to_string(), won, attempts, lives, undos: 0 }
+    }
+
+    pub fn with_undos(mut self, undos: u32) -> GameRecord {
+        self.undos = undos;
+        self
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{},{},{},{},{},{}", self.timestamp, self.word, if self.won { "won" } else { "lost" }, self.attempts, self.lives, self.undos)
+    }
+
+    pub fn from_line(line: &str) -> Option<GameRecord> {
+        let columns: Vec<&str> = line.trim().split(',').collect();
+        // Records written before undo existed have no undo column.
+        let (timestamp, word, result, attempts, lives, undos) = match columns.as_slice() {
+            [timestamp, word, result, attempts, lives] => (timestamp, word, result, attempts, lives, "0"),
+            [timestamp, word, result, attempts, lives, undos] => (timestamp, word, result, attempts, lives, *undos),
+            _ => return None,
+        };
+        Some(GameRecord {
+            timestamp: timestamp.parse().ok()?,
+            word: word.to_string(),
+            won: *result == "won",
+            attempts: attempts.parse().ok()?,
+            lives: lives.parse().ok()?,
+            undos: undos.parse().ok()?,
+        })
+    }
+}
+
+impl Summary {
+    pub fn from_records(records: &[GameRecord]) -> Summary {
+        let mut summary = Summary::default();
+        let mut streak: usize = 0;
+        for record in records.iter() {
+            if record.undos > 0 {
+                summary.practice += 1;
+                continue;
+            }
+            summary.played += 1;
+            if record.won {
+                summary.won += 1;
+                streak += 1;
+                summary.best_streak = summary.best_streak.max(streak);
+            } else {
+                summary.lost += 1;
+                streak = 0;
+            }
+        }
+        summary.current_streak = streak;
+        summary
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.played == 0 {
+            return 0.0;
+        }
+        self.won as f64 * 100.0 / self.played as f64
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default()
+}
+
+pub fn data_dir() -> PathBuf {
+    if let Some(data_home) = env::var_os("XDG_DATA_HOME").filter(|value| !value.is_empty()) {
+        return PathBuf::from(data_home).join("jogo_da_forca");
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")).unwrap_or_default();
+    PathBuf::from(home).join(".local").join("share").join("jogo_da_forca")
+}
+
+pub fn stats_path() -> PathBuf {
+    data_dir().join(STATS_FILE)
+}
+
+pub fn record_game(path: &Path, record: &GameRecord) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", record.to_line())
+}
+
+pub fn load_records(path: &Path) -> Result<Vec<GameRecord>, Error> {
+    match read_to_string(path) {
+        Ok(content) => Ok(content.lines().filter_map(GameRecord::from_line).collect()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn print_summary(summary: &Summary, logger: &mut dyn Logger) {
+    logger.print(format_args!("Played: {}", summary.played));
+    logger.print(format_args!("Won: {}", summary.won));
+    logger.print(format_args!("Lost: {}", summary.lost));
+    logger.print(format_args!("Win rate: {:.1}%", summary.win_rate()));
+    logger.print(format_args!("Current streak: {}", summary.current_streak));
+    logger.print(format_args!("Best streak: {}", summary.best_streak));
+    if summary.practice > 0 {
+        logger.print(format_args!("Practice games with undo (not counted): {}", summary.practice));
+    }
+}
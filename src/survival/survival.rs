@@ -0,0 +1,114 @@
+use rand::Rng;
+
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::logger::logger::Logger;
+use crate::random_word::word_list::{choose_word, Difficulty, Word, WordFilter};
+
+/// Words solved before the difficulty goes up a level.
+pub const DEFAULT_RAMP: u32 = 3;
+/// Category the runs are recorded under on the leaderboard.
+pub const SURVIVAL_CATEGORY: &str = "survival";
+
+/// An endless run where the lives carry over from word to word: every miss costs one and every solved word refunds one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurvivalRun {
+    max_lives: u8,
+    lives: u8,
+    ramp: u32,
+    solved: u32,
+    score: u32,
+    used: Vec<String>,
+}
+
+impl SurvivalRun {
+    pub fn new(lives: u8) -> SurvivalRun {
+        let lives = lives.max(1);
+        SurvivalRun { max_lives: lives, lives, ramp: DEFAULT_RAMP, solved: 0, score: 0, used: Vec::new() }
+    }
+
+    pub fn with_ramp(mut self, ramp: u32) -> SurvivalRun {
+        self.ramp = ramp.max(1);
+        self
+    }
+
+    pub fn lives(&self) -> u8 {
+        self.lives
+    }
+
+    pub fn max_lives(&self) -> u8 {
+        self.max_lives
+    }
+
+    pub fn solved(&self) -> u32 {
+        self.solved
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.lives == 0
+    }
+
+    /// The word being played, starting at 1.
+    pub fn round(&self) -> u32 {
+        self.solved + 1
+    }
+
+    /// Easy words first, then medium and hard ones every `ramp` solved words.
+    pub fn difficulty(&self) -> Difficulty {
+        match self.solved / self.ramp {
+            0 => Difficulty::Easy,
+            1 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+
+    /// The lives already lost, so the next game starts with the gibbet as the last one left it.
+    pub fn attempts(&self) -> u8 {
+        self.max_lives - self.lives
+    }
+
+    /// A word of the current difficulty not played yet in this run, relaxing the difficulty and then the repetition
+    /// when the list runs out of them.
+    pub fn choose<'a, R: Rng + ?Sized>(&mut self, words: &'a [Word], filter: &WordFilter, rng: &mut R) -> Option<&'a Word> {
+        let fresh: Vec<Word> = words.iter().filter(|word| !self.used.contains(&word.text.to_lowercase())).cloned().collect();
+        let ramped = WordFilter { difficulty: Some(self.difficulty()), ..filter.clone() };
+        let text = choose_word(&fresh, &ramped, rng)
+            .or_else(|| choose_word(&fresh, filter, rng))
+            .or_else(|| choose_word(words, filter, rng))?
+            .text.clone();
+        self.used.push(text.to_lowercase());
+        words.iter().find(|word| word.text == text)
+    }
+
+    pub fn new_game(&self, word: &str) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), self.attempts());
+        game.set_lives(self.max_lives);
+        game
+    }
+
+    /// Carries the lives left by a finished game over to the next word.
+    pub fn finish_word(&mut self, game: &HangmanGame, score: u32) {
+        match game.status() {
+            GameStatus::Won => {
+                self.solved += 1;
+                self.score += score;
+                self.lives = (game.lives().saturating_sub(game.attempts()) + 1).min(self.max_lives);
+            }
+            GameStatus::Lost => self.lives = 0,
+            GameStatus::InProgress => (),
+        }
+    }
+}
+
+pub fn print_round(run: &SurvivalRun, logger: &mut dyn Logger) {
+    logger.print(format_args!("Word {} ({}) - lives {}/{}", run.round(), run.difficulty(), run.lives(), run.max_lives()));
+}
+
+pub fn print_run_summary(run: &SurvivalRun, logger: &mut dyn Logger) {
+    logger.print(format_args!("Run over!"));
+    logger.print(format_args!("Words solved: {}", run.solved()));
+    logger.print(format_args!("Score: {}", run.score()));
+}
@@ -0,0 +1,144 @@
+#[cfg(feature = "terminal")]
+use std::collections::VecDeque;
+#[cfg(feature = "terminal")]
+use std::io::BufRead;
+#[cfg(feature = "terminal")]
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+#[cfg(feature = "terminal")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "terminal")]
+use std::thread;
+use std::time::Duration;
+
+#[cfg(feature = "terminal")]
+use crate::batch::batch::read_line;
+use crate::logger::logger::Logger;
+
+/// How often a timed game wakes up to redraw its clock while waiting for a guess.
+pub const TICK: Duration = Duration::from_millis(250);
+
+/// A time limit measured in milliseconds since the epoch, so it can be checked against any clock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Countdown {
+    started: u64,
+    limit: u64,
+}
+
+impl Countdown {
+    pub fn new(limit: Duration, now: u64) -> Countdown {
+        Countdown { started: now, limit: u64::try_from(limit.as_millis()).unwrap_or(u64::MAX) }
+    }
+
+    pub fn restart(&mut self, now: u64) {
+        self.started = now;
+    }
+
+    pub fn remaining(&self, now: u64) -> u64 {
+        self.started.saturating_add(self.limit).saturating_sub(now)
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.remaining(now) == 0
+    }
+}
+
+/// `m:ss`, rounding up so the clock only shows `0:00` once the time is over.
+pub fn format_clock(millis: u64) -> String {
+    let seconds = millis.div_ceil(1000);
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// The clock shown while waiting for a guess, e.g. `Time left: 0:09 to guess, 1:23 in the blitz`.
+pub fn format_time_left(guess: Option<u64>, total: Option<u64>) -> String {
+    let parts: Vec<String> = [
+        guess.map(|millis| format!("{} to guess", format_clock(millis))),
+        total.map(|millis| format!("{} in the blitz", format_clock(millis))),
+    ].into_iter().flatten().collect();
+    format!("Time left: {}", parts.join(", "))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Poll {
+    Line(String),
+    Pending,
+    Closed,
+}
+
+/// Reads lines on a background thread, so a timed game can keep its clock running while the player types.
+///
+/// Clones share the thread, which lets several games of a blitz read from the same stdin.
+#[cfg(feature = "terminal")]
+#[derive(Debug, Clone)]
+pub struct LineReader {
+    receiver: Arc<Mutex<Receiver<String>>>,
+    interactive: bool,
+}
+
+#[cfg(feature = "terminal")]
+impl PartialEq for LineReader {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.receiver, &other.receiver)
+    }
+}
+
+#[cfg(feature = "terminal")]
+impl LineReader {
+    pub fn spawn<R: BufRead + Send + 'static>(mut reader: R, interactive: bool) -> LineReader {
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            while let Some(line) = read_line(&mut reader) {
+                if sender.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+        LineReader { receiver: Arc::new(Mutex::new(receiver)), interactive }
+    }
+
+    /// A reader that already holds all its lines, for scripted guesses.
+    pub fn from_lines(lines: VecDeque<String>) -> LineReader {
+        let (sender, receiver) = channel();
+        for line in lines.into_iter() {
+            // The receiver is alive until the end of this function, sending cannot fail.
+            let _ = sender.send(line);
+        }
+        LineReader { receiver: Arc::new(Mutex::new(receiver)), interactive: false }
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    pub fn poll(&self, timeout: Duration) -> Poll {
+        let Ok(receiver) = self.receiver.lock() else {
+            return Poll::Closed;
+        };
+        match receiver.recv_timeout(timeout) {
+            Ok(line) => Poll::Line(line),
+            Err(RecvTimeoutError::Timeout) => Poll::Pending,
+            Err(RecvTimeoutError::Disconnected) => Poll::Closed,
+        }
+    }
+}
+
+/// Words played during a blitz and the points they earned.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BlitzTally {
+    pub played: u32,
+    pub solved: u32,
+    pub score: u32,
+}
+
+impl BlitzTally {
+    pub fn add(&mut self, won: bool, score: u32) {
+        self.played += 1;
+        self.solved += won as u32;
+        self.score += score;
+    }
+}
+
+pub fn print_blitz_summary(tally: &BlitzTally, time_is_up: bool, logger: &mut dyn Logger) {
+    logger.print(format_args!("{}", if time_is_up { "Time is up!" } else { "Blitz over" }));
+    logger.print(format_args!("Words solved: {} of {}", tally.solved, tally.played));
+    logger.print(format_args!("Score: {}", tally.score));
+}
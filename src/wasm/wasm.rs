@@ -0,0 +1,118 @@
+use rand::thread_rng;
+use wasm_bindgen::prelude::*;
+
+use crate::batch::batch::masked_word;
+use crate::game::game::{Game, GameStatus, HangmanGame};
+use crate::puppet::gibbet::Gibbet;
+use crate::random_word::word_list::{built_in_words, choose_word, WordFilter};
+use crate::solver::solver;
+
+/// A game driven from JavaScript, e.g. `const game = WasmGame.random("pt", 6); game.guess("a");`.
+#[wasm_bindgen]
+pub struct WasmGame {
+    game: HangmanGame,
+}
+
+#[wasm_bindgen]
+impl WasmGame {
+    #[wasm_bindgen(constructor)]
+    pub fn new(word: &str, lives: u8) -> WasmGame {
+        WasmGame { game: HangmanGame::headless(word, lives) }
+    }
+
+    /// Picks one of the built-in words of a language, or returns `undefined` if there is none.
+    pub fn random(lang: &str, lives: u8) -> Option<WasmGame> {
+        let words = built_in_words();
+        let filter = WordFilter { language: Some(lang.to_string()), ..WordFilter::default() };
+        choose_word(&words, &filter, &mut thread_rng()).map(|word| WasmGame::new(&word.text, lives))
+    }
+
+    /// Guesses a letter and returns whether it is in the word, longer input is taken as a guess of the whole word.
+    ///
+    /// A letter guessed before costs nothing again.
+    pub fn guess(&mut self, letter: &str) -> bool {
+        let letter = letter.trim().to_lowercase();
+        if self.game.status() != GameStatus::InProgress || letter.is_empty() {
+            return false;
+        }
+        let snapshot = self.game.snapshot();
+        if snapshot.hits.contains(&letter) || snapshot.errors.contains(&letter) {
+            return snapshot.hits.contains(&letter);
+        }
+        self.game.verify_answer(letter);
+        self.game.attempts() == snapshot.attempts
+    }
+
+    #[wasm_bindgen(js_name = guessWord)]
+    pub fn guess_word(&mut self, word: &str) -> bool {
+        self.game.status() == GameStatus::InProgress && self.game.guess_word(word.trim().to_string())
+    }
+
+    pub fn hint(&mut self) -> Option<String> {
+        if self.game.status() != GameStatus::InProgress {
+            return None;
+        }
+        self.game.use_hint()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn word(&self) -> String {
+        masked_word(&self.game.snapshot().formed_word_by_hits)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn hits(&self) -> Vec<String> {
+        self.game.snapshot().hits
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn misses(&self) -> Vec<String> {
+        self.game.snapshot().errors
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lives(&self) -> u8 {
+        self.game.lives()
+    }
+
+    #[wasm_bindgen(getter, js_name = livesLeft)]
+    pub fn lives_left(&self) -> u8 {
+        self.game.lives().saturating_sub(self.game.attempts())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn stage(&self) -> u8 {
+        self.game.stage()
+    }
+
+    /// `in_progress`, `won` or `lost`.
+    #[wasm_bindgen(getter)]
+    pub fn status(&self) -> String {
+        match self.game.status() {
+            GameStatus::InProgress => "in_progress".to_string(),
+            GameStatus::Won => "won".to_string(),
+            GameStatus::Lost => "lost".to_string(),
+        }
+    }
+
+    /// The word, only once the game is over.
+    #[wasm_bindgen(getter)]
+    pub fn answer(&self) -> Option<String> {
+        (self.game.status() != GameStatus::InProgress).then(|| self.game.word().to_lowercase())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn gibbet(&self) -> String {
+        Gibbet::from_attempts(self.game.stage()).map(|gibbet| gibbet.to_string()).unwrap_or_default()
+    }
+}
+
+#[wasm_bindgen(js_name = gibbetStage)]
+pub fn gibbet_stage(attempts: u8, lives: u8) -> u8 {
+    crate::gibbet_stage(attempts, lives)
+}
+
+#[wasm_bindgen(js_name = matchesPattern)]
+pub fn matches_pattern(word: &str, pattern: &str, misses: &str) -> bool {
+    solver::matches_pattern(word, pattern, misses)
+}
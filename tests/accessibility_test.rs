@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use std::fmt::Arguments;
+    use jogo_da_forca::accessibility::accessibility::{announce_hit, announce_miss, describe_gibbet, describe_positions, print_accessible_game, spell_word};
+    use jogo_da_forca::logger::logger::Logger;
+
+    #[derive(Default)]
+    struct DummyLogger(Vec<String>);
+    impl Logger for DummyLogger {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.push(value.to_string());
+        }
+    }
+
+    #[test]
+    fn describe_gibbet_when_attempt_is_zero_it_passes() {
+        let expected = "0 of 6 body parts drawn. The gibbet is empty.";
+        let actual = describe_gibbet(0);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn describe_gibbet_when_attempt_is_three_it_passes() {
+        let expected = "3 of 6 body parts drawn: head, right arm and left arm.";
+        let actual = describe_gibbet(3);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn describe_gibbet_when_attempt_is_one_it_passes() {
+        let expected = "1 of 6 body parts drawn: head.";
+        let actual = describe_gibbet(1);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn spell_word_reads_blanks_it_passes() {
+        let formed_word_by_hits: Vec<String> = vec!["a".to_string(), " ".to_string(), "p".to_string(), " ".to_string()];
+        let expected = "The word has 4 letters: 1 A, 2 blank, 3 P, 4 blank.";
+        let actual = spell_word(&formed_word_by_hits);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn describe_positions_when_there_is_one_position_it_passes() {
+        let expected = "position 3";
+        let actual = describe_positions(&[2]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn describe_positions_when_there_are_many_positions_it_passes() {
+        let expected = "positions 1, 3 and 4";
+        let actual = describe_positions(&[0, 2, 3]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn announce_hit_it_passes() {
+        let expected = "Correct! The letter P is at positions 2 and 3.";
+        let actual = announce_hit("p", &[1, 2]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn announce_miss_it_passes() {
+        let expected = "Wrong. There is no letter X in the word. 2 of 6 body parts drawn: head and right arm. 4 attempts left.";
+        let actual = announce_miss("x", 2, 6);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn announce_miss_when_one_attempt_is_left_it_passes() {
+        let expected = "Wrong. There is no letter X in the word. 5 of 6 body parts drawn: head, right arm, left arm, stem and right leg. 1 attempt left.";
+        let actual = announce_miss("x", 5, 6);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn print_accessible_game_it_passes() {
+        let mut logger = DummyLogger::default();
+        let formed_word_by_hits: Vec<String> = vec!["d".to_string(), " ".to_string(), " ".to_string()];
+        let errors: Vec<String> = vec!["x".to_string()];
+        print_accessible_game(&formed_word_by_hits, &errors, 1, &mut logger);
+        assert_eq!("1 of 6 body parts drawn: head.", logger.0[0]);
+        assert_eq!("The word has 3 letters: 1 D, 2 blank, 3 blank.", logger.0[1]);
+        assert_eq!("Wrong letters: X.", logger.0[2]);
+    }
+
+    #[test]
+    fn print_accessible_game_when_there_are_no_errors_it_passes() {
+        let mut logger = DummyLogger::default();
+        let formed_word_by_hits: Vec<String> = vec![" ".to_string()];
+        print_accessible_game(&formed_word_by_hits, &Vec::new(), 0, &mut logger);
+        assert_eq!("No wrong letters yet.", logger.0[2]);
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_file;
+    use jogo_da_forca::achievements::achievements::{load_unlocks, print_trophies, record_unlocks, Achievement, AchievementTracker, Unlock};
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::leaderboard::leaderboard::ScoreEntry;
+    use jogo_da_forca::leaderboard::score::Score;
+    use jogo_da_forca::logger::logger::Level;
+    use jogo_da_forca::logger::sinks::MemoryLogger;
+    use jogo_da_forca::random_word::word_list::Word;
+
+    fn get_entry(player: &str, word: &str, won: bool) -> ScoreEntry {
+        ScoreEntry::new(1, player, word, "animal", won, &Score::default())
+    }
+
+    fn get_words() -> Vec<Word> {
+        vec![Word::new("cat", "animal", "en"), Word::new("dog", "animal", "en"), Word::new("apple", "fruit", "en")]
+    }
+
+    fn play(word: &str, lives: u8, script: &str, tracker: AchievementTracker, logger: &MemoryLogger) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_lives(lives);
+        game.set_batch(Input::from_script(script));
+        game.set_logger(Box::new(logger.clone()));
+        game.set_achievements(tracker);
+        game.play();
+        game
+    }
+
+    #[test]
+    fn achievement_to_string_and_back_it_passes() {
+        let achievements = vec![Achievement::Flawless, Achievement::LastLife, Achievement::Streak, Achievement::LongWord, Achievement::Category("animal".to_string())];
+        let actual: Vec<Achievement> = achievements.iter().map(|achievement| achievement.to_string().parse().unwrap()).collect();
+        assert_eq!(achievements, actual);
+    }
+
+    #[test]
+    fn unlock_to_line_and_back_it_passes() {
+        let expected = Unlock::new(5, "ana", Achievement::Category("animal".to_string()));
+        let actual = Unlock::from_line(&expected.to_line()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn from_history_it_passes() {
+        let entries = vec![get_entry("ana", "cat", false), get_entry("ana", "dog", true), get_entry("bia", "cat", true), get_entry("ana", "cow", true)];
+        let unlocks = vec![Unlock::new(1, "ana", Achievement::Flawless), Unlock::new(1, "bia", Achievement::LastLife)];
+        let tracker = AchievementTracker::from_history("ana", &entries, &unlocks, &get_words()[0], &get_words());
+        let expected = (vec![Achievement::Flawless], 2, vec!["cat".to_string(), "dog".to_string()], vec!["dog".to_string(), "cow".to_string()]);
+        let actual = (tracker.unlocked, tracker.streak, tracker.category_words, tracker.solved);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn flawless_win_it_passes() {
+        let logger = MemoryLogger::new();
+        let game = play("dog", 6, "d\no\ng", AchievementTracker::default(), &logger);
+        let expected = vec![Achievement::Flawless];
+        assert_eq!(expected, game.unlocked_achievements());
+        assert_eq!(Some(&"achievement flawless".to_string()), logger.messages(Level::Info).last());
+    }
+
+    #[test]
+    fn win_with_one_life_left_it_passes() {
+        let logger = MemoryLogger::new();
+        let game = play("dog", 2, "x\nd\no\ng", AchievementTracker::default(), &logger);
+        let expected = vec![Achievement::LastLife];
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn tenth_win_in_a_row_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut tracker = AchievementTracker::default();
+        tracker.streak = 9;
+        let game = play("dog", 6, "x\nd\no\ng", tracker, &logger);
+        let expected = vec![Achievement::Streak];
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn long_word_it_passes() {
+        let logger = MemoryLogger::new();
+        let game = play("extraordinary", 6, "q\nextraordinary", AchievementTracker::default(), &logger);
+        let expected = vec![Achievement::LongWord];
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn last_word_of_a_category_it_passes() {
+        let logger = MemoryLogger::new();
+        let tracker = AchievementTracker::from_history("ana", &[get_entry("ana", "dog", true)], &[], &get_words()[0], &get_words());
+        let game = play("cat", 6, "x\ncat", tracker, &logger);
+        let expected = vec![Achievement::Category("animal".to_string())];
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn achievements_already_unlocked_are_not_unlocked_again_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut tracker = AchievementTracker::default();
+        tracker.unlocked = vec![Achievement::Flawless];
+        let game = play("dog", 6, "d\no\ng", tracker, &logger);
+        let expected: Vec<Achievement> = Vec::new();
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn lost_game_unlocks_nothing_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut tracker = AchievementTracker::default();
+        tracker.streak = 20;
+        let game = play("dog", 1, "x", tracker, &logger);
+        let expected: Vec<Achievement> = Vec::new();
+        assert_eq!(expected, game.unlocked_achievements());
+    }
+
+    #[test]
+    fn record_unlocks_and_load_unlocks_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_achievements_{}.csv", std::process::id()));
+        let unlocks = vec![Unlock::new(1, "ana", Achievement::Flawless), Unlock::new(2, "ana", Achievement::LongWord)];
+        record_unlocks(&path, &unlocks).unwrap();
+        let actual = load_unlocks(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(unlocks, actual);
+    }
+
+    #[test]
+    fn print_trophies_it_passes() {
+        let mut logger = MemoryLogger::new();
+        let unlocks = vec![Unlock::new(1, "ana", Achievement::Flawless), Unlock::new(1, "ana", Achievement::Category("animal".to_string())), Unlock::new(1, "bia", Achievement::Streak)];
+        print_trophies("ana", &unlocks, &mut logger);
+        let expected = vec![
+            "Trophies of ana (2 unlocked)",
+            "[x] Flawless - win without a miss or a hint",
+            "[ ] Close call - win with one life left",
+            "[ ] On fire - win 10 games in a row",
+            "[ ] Lexicographer - solve a word of 12 letters or more",
+            "[x] Master of animal - solve every word of the animal category",
+        ];
+        assert_eq!(expected, logger.messages(Level::Info));
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use std::fmt::Arguments;
+    use std::time::Duration;
+    use jogo_da_forca::animation::animation::{celebrate, draw_limb, get_strokes, shift_line, swing, Frame, FrameScheduler};
+    use jogo_da_forca::logger::logger::Logger;
+    use jogo_da_forca::puppet::gibbet::Gibbet;
+
+    #[derive(Default)]
+    struct DummyLogger(Vec<String>);
+    impl Logger for DummyLogger {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.push(value.to_string());
+        }
+    }
+
+    #[test]
+    fn get_strokes_when_head_is_added_returns_one_stroke_it_passes() {
+        let from = Gibbet::Gibbet.to_string();
+        let to = Gibbet::Head.to_string();
+        let actual = get_strokes(&from, &to);
+        assert_eq!(1, actual.len());
+        assert_eq!('⚉', actual[0].2);
+    }
+
+    #[test]
+    fn get_strokes_when_stages_are_equal_is_empty_it_passes() {
+        let stage = Gibbet::HeadAndRightArm.to_string();
+        let expected: bool = true;
+        let actual = get_strokes(&stage, &stage).is_empty();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn draw_limb_last_frame_is_the_next_stage_it_passes() {
+        let expected = Gibbet::HeadRightArmAndLeftArm.to_string();
+        let frames = draw_limb(2, 3);
+        assert_eq!(expected, frames.last().unwrap().content);
+    }
+
+    #[test]
+    fn draw_limb_draws_stroke_by_stroke_it_passes() {
+        let strokes = get_strokes(&Gibbet::HeadRightArmLeftArmStemAndRightLeg.to_string(), &Gibbet::HeadRightArmLeftArmStemRightLegAndLeftLeg.to_string());
+        let frames = draw_limb(5, 6);
+        assert_eq!(strokes.len() + 1, frames.len());
+    }
+
+    #[test]
+    fn draw_limb_when_attempts_are_invalid_is_empty_it_passes() {
+        let expected: bool = true;
+        let actual = draw_limb(6, 7).is_empty();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shift_line_to_the_right_it_passes() {
+        let expected = "    |                ⚉";
+        let actual = shift_line("    |               ⚉", 1);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shift_line_to_the_left_it_passes() {
+        let expected = "    |              ⚉";
+        let actual = shift_line("    |               ⚉", -1);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shift_line_when_it_is_not_part_of_the_gibbet_it_passes() {
+        let expected = "Jogo da Forca";
+        let actual = shift_line("Jogo da Forca", 1);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn swing_moves_the_puppet_it_passes() {
+        let frames = swing();
+        let expected: bool = true;
+        let actual = frames.iter().any(|frame| frame.content.contains("    |                ⚉"));
+        assert_eq!(expected, actual);
+        assert_eq!(Gibbet::HeadRightArmLeftArmStemRightLegAndLeftLeg.to_string(), frames.last().unwrap().content);
+    }
+
+    #[test]
+    fn celebrate_shows_the_word_it_passes() {
+        let expected: bool = true;
+        let actual = celebrate("Apple").iter().all(|frame| frame.content.contains("APPLE"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn frame_scheduler_when_disabled_prints_nothing_it_passes() {
+        let mut logger = DummyLogger::default();
+        let scheduler = FrameScheduler::disabled();
+        let expected: bool = true;
+        scheduler.play(&draw_limb(0, 1), &mut logger);
+        assert_eq!(expected, logger.0.is_empty());
+        assert_eq!(!expected, scheduler.is_enabled());
+    }
+
+    #[test]
+    fn frame_scheduler_delay_of_when_speed_is_doubled_it_passes() {
+        let scheduler = FrameScheduler::new(true, 2.0);
+        let frame = Frame::new(String::new(), Duration::from_millis(100));
+        let expected = Duration::from_millis(50);
+        let actual = scheduler.delay_of(&frame);
+        assert_eq!(expected, actual);
+    }
+}
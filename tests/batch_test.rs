@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::fmt::Arguments;
+    use jogo_da_forca::batch::batch::{format_state, masked_word, print_guess, print_hint, print_result, print_undo, read_guesses, read_line, Input};
+    use jogo_da_forca::config::config::KeyBindings;
+    use jogo_da_forca::game::game::{Game, GameStatus, HangmanGame};
+    use jogo_da_forca::logger::logger::Logger;
+
+    #[derive(Default)]
+    struct DummyLogger(Vec<String>);
+    impl Logger for DummyLogger {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.push(value.to_string());
+        }
+    }
+
+    fn play_script(word: &str, script: &str) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_batch(Input::from_script(script));
+        game.play();
+        game
+    }
+
+    #[test]
+    fn read_guesses_skips_blank_lines_and_comments_it_passes() {
+        let expected: VecDeque<String> = VecDeque::from(vec!["a".to_string(), "b".to_string()]);
+        let actual = read_guesses("# guesses\na\n\n  b  \n");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn read_line_it_passes() {
+        let expected = Some("a".to_string());
+        let actual = read_line(&b"a\r\nb\n"[..]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn read_line_when_input_is_over_is_none_it_passes() {
+        let expected: Option<String> = None;
+        let actual = read_line(&b""[..]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn input_lines_next_line_it_passes() {
+        let mut input = Input::from_script("a\nb");
+        assert_eq!(Some("a".to_string()), input.next_line());
+        assert_eq!(Some("b".to_string()), input.next_line());
+        assert_eq!(None, input.next_line());
+    }
+
+    #[test]
+    fn format_state_it_passes() {
+        let formed_word_by_hits: Vec<String> = vec!["d".to_string(), " ".to_string(), " ".to_string()];
+        let errors: Vec<String> = vec!["x".to_string(), "z".to_string()];
+        let expected = "state word=d__ misses=x,z lives=4/6";
+        let actual = format_state(&formed_word_by_hits, &errors, 2, 6);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn print_batch_lines_it_passes() {
+        let mut logger = DummyLogger::default();
+        print_guess("A", true, &mut logger);
+        print_guess("x", false, &mut logger);
+        print_hint("p", &mut logger);
+        print_result("won", "Apple", &mut logger);
+        assert_eq!(vec!["guess a hit", "guess x miss", "hint p", "result won word=apple"], logger.0);
+    }
+
+    #[test]
+    fn play_script_when_guesses_solve_the_word_is_won_it_passes() {
+        let game = play_script("Dog", "x\nd\no\ng\n");
+        assert_eq!(GameStatus::Won, game.status());
+        assert_eq!(1, game.attempts());
+    }
+
+    #[test]
+    fn play_script_when_the_word_starts_with_a_capital_with_an_accent_is_won_it_passes() {
+        let game = play_script("Águia", "á\ng\nu\ni\na\n");
+        let expected = (GameStatus::Won, 0);
+        let actual = (game.status(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_script_when_guesses_miss_is_lost_it_passes() {
+        let game = play_script("Dog", "q\nw\nr\nt\ny\nu\nd\n");
+        assert_eq!(GameStatus::Lost, game.status());
+    }
+
+    #[test]
+    fn play_script_when_guesses_run_out_is_in_progress_it_passes() {
+        let game = play_script("Dog", "d\n");
+        assert_eq!(GameStatus::InProgress, game.status());
+    }
+
+    #[test]
+    fn play_script_with_quit_binding_is_lost_it_passes() {
+        let game = play_script("Dog", "d\n:q\n");
+        assert_eq!(GameStatus::Lost, game.status());
+    }
+
+    #[test]
+    fn play_script_with_hints_it_passes() {
+        let game = play_script("Dog", ":h\n:h\n:h\n");
+        assert_eq!(GameStatus::Won, game.status());
+        assert_eq!(3, game.hints_used());
+    }
+
+    fn play_script_with_keys(word: &str, script: &str, keys: KeyBindings) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_key_bindings(keys);
+        game.set_batch(Input::from_script(script));
+        game.play();
+        game
+    }
+
+    #[test]
+    fn play_script_with_configured_hint_binding_it_passes() {
+        let keys = KeyBindings { hint: ":dica".to_string(), ..KeyBindings::default() };
+        let game = play_script_with_keys("Dog", ":dica\n:dica\n:dica\n", keys);
+        let expected = (GameStatus::Won, 3, 0);
+        let actual = (game.status(), game.hints_used(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_script_with_configured_quit_binding_is_lost_it_passes() {
+        let keys = KeyBindings { quit: ":sair".to_string(), ..KeyBindings::default() };
+        let game = play_script_with_keys("Dog", "d\n:sair\no\ng\n", keys);
+        let expected = (GameStatus::Lost, "d__".to_string());
+        let actual = (game.status(), masked_word(&game.snapshot().formed_word_by_hits));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_script_with_configured_bindings_leaves_the_default_ones_unbound_it_passes() {
+        let keys = KeyBindings { quit: ":sair".to_string(), hint: ":dica".to_string(), ..KeyBindings::default() };
+        let game = play_script_with_keys("Dog", ":h\n:q\n", keys);
+        let expected = (GameStatus::InProgress, 0);
+        let actual = (game.status(), game.hints_used());
+        assert_eq!(expected, actual);
+    }
+
+    fn play_practice(word: &str, script: &str) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_batch(Input::from_script(script));
+        game.set_practice(true);
+        game.play();
+        game
+    }
+
+    #[test]
+    fn undo_restores_the_state_before_the_last_guess_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        let before = game.snapshot();
+        game.verify_answer("x".to_string());
+        let expected = (true, before, 1);
+        let actual = (game.undo(), game.snapshot(), game.undos());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_counts_past_a_byte_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        for _ in 0..300 {
+            game.verify_answer("x".to_string());
+            game.undo();
+        }
+        let expected: (u32, u8) = (300, 0);
+        let actual = (game.undos(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_after_a_timeout_gives_back_only_the_life_it_cost_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        game.verify_answer("x".to_string());
+        game.time_out();
+        let expected = (true, 1, vec!["x".to_string()]);
+        let actual = (game.undo(), game.attempts(), game.snapshot().errors);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_after_a_hint_keeps_the_hint_used_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        game.use_hint();
+        let expected = (true, "___".to_string(), 1);
+        let actual = (game.undo(), masked_word(&game.snapshot().formed_word_by_hits), game.hints_used());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_when_nothing_was_guessed_is_refused_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        let expected: (bool, u32) = (false, 0);
+        let actual = (game.undo(), game.undos());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_in_ranked_game_is_refused_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.verify_answer("x".to_string());
+        let expected: (bool, u8) = (false, 1);
+        let actual = (game.undo(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn undo_after_the_game_is_over_is_refused_it_passes() {
+        let mut game = HangmanGame::headless("dog", 6);
+        game.set_practice(true);
+        game.guess_word("dog".to_string());
+        let expected: (bool, GameStatus) = (false, GameStatus::Won);
+        let actual = (game.undo(), game.status());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_practice_script_with_undo_it_passes() {
+        let game = play_practice("Dog", "x\n:u\nd\no\ng\n");
+        let expected = (GameStatus::Won, 0, 1);
+        let actual = (game.status(), game.attempts(), game.undos());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_script_with_undo_in_ranked_game_keeps_the_miss_it_passes() {
+        let game = play_script("Dog", "x\n:u\nd\no\ng\n");
+        let expected = (GameStatus::Won, 1, 0);
+        let actual = (game.status(), game.attempts(), game.undos());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn print_undo_it_passes() {
+        let mut logger = DummyLogger::default();
+        print_undo(true, &mut logger);
+        print_undo(false, &mut logger);
+        assert_eq!(vec!["undo".to_string(), "undo unavailable".to_string()], logger.0);
+    }
+}
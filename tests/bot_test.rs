@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use jogo_da_forca::bot::bot::{format_compact, parse_command, Bot, BotCommand};
+    use jogo_da_forca::bot::transport::{parse_chat_line, ChatMessage, LineTransport, MockTransport, Transport};
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::random_word::word_list::{Word, WordFilter};
+
+    fn get_bot() -> Bot {
+        let words = vec![Word::new("Dog", "animal", "en")];
+        Bot::new(words, WordFilter::default(), 2, Some(1))
+    }
+
+    fn say(bot: &mut Bot, channel: &str, text: &str) -> Option<String> {
+        bot.handle(&ChatMessage::new(channel, "ana", text))
+    }
+
+    #[test]
+    fn parse_command_it_passes() {
+        let expected = vec![Some(BotCommand::Start), Some(BotCommand::Show), Some(BotCommand::Guess("e".to_string())), Some(BotCommand::Hint), None, None];
+        let actual: Vec<Option<BotCommand>> = ["!hangman start", "!HANGMAN", "!guess E", "!hint", "hello", "!guess"].iter().map(|text| parse_command(text, "!")).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_compact_it_passes() {
+        let mut game = HangmanGame::headless("Dog", 6);
+        game.verify_answer("o".to_string());
+        game.verify_answer("z".to_string());
+        let expected = "_ O _ | misses: Z | [#-----] 1/6";
+        let actual = format_compact(&game);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn bot_plays_a_game_it_passes() {
+        let mut bot = get_bot();
+        let expected = vec![
+            Some("No game here, start one with !hangman start".to_string()),
+            Some("New game! _ _ _ | misses: - | [--] 0/2".to_string()),
+            Some("A game is already running: _ _ _ | misses: - | [--] 0/2".to_string()),
+            Some("ana: D is in the word! D _ _ | misses: - | [--] 0/2".to_string()),
+            Some("D was already guessed.".to_string()),
+            Some("ana: no Z. D _ _ | misses: Z | [#-] 1/2".to_string()),
+            None,
+            Some("ana solved it! The word was DOG.".to_string()),
+        ];
+        let actual: Vec<Option<String>> = ["!guess d", "!hangman start", "!hangman start", "!guess d", "!guess d", "!guess z", "nice", "!guess dog"].iter().map(|text| say(&mut bot, "#sala", text)).collect();
+        assert_eq!(expected, actual);
+        assert!(bot.game("#sala").is_none());
+    }
+
+    #[test]
+    fn bot_lost_game_it_passes() {
+        let mut bot = get_bot();
+        say(&mut bot, "#sala", "!hangman start");
+        say(&mut bot, "#sala", "!guess x");
+        let expected = Some("ana: no Y. Game over, the word was DOG.".to_string());
+        let actual = say(&mut bot, "#sala", "!guess y");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn bot_guess_when_not_letters_keeps_the_lives_it_passes() {
+        let mut bot = get_bot();
+        say(&mut bot, "#sala", "!hangman start");
+        let expected = vec![Some("ana: guess letters only, !guess <letter or word>".to_string()); 3];
+        let actual: Vec<Option<String>> = ["!guess 1", "!guess ?", "!guess d0g"].iter().map(|text| say(&mut bot, "#sala", text)).collect();
+        assert_eq!(expected, actual);
+        assert_eq!(0, bot.game("#sala").unwrap().attempts());
+    }
+
+    #[test]
+    fn one_game_per_channel_it_passes() {
+        let mut bot = get_bot();
+        say(&mut bot, "#a", "!hangman start");
+        say(&mut bot, "#b", "!hangman start");
+        say(&mut bot, "#a", "!guess z");
+        let expected: (u8, u8) = (1, 0);
+        let actual = (bot.game("#a").unwrap().attempts(), bot.game("#b").unwrap().attempts());
+        assert_eq!(expected, actual);
+        assert_eq!(Some("Game stopped, the word was DOG.".to_string()), say(&mut bot, "#b", "!hangman stop"));
+        assert!(bot.game("#b").is_none());
+    }
+
+    #[test]
+    fn custom_prefix_it_passes() {
+        let mut bot = get_bot().with_prefix(".");
+        assert_eq!(None, say(&mut bot, "#sala", "!hangman start"));
+        assert_eq!(Some("New game! _ _ _ | misses: - | [--] 0/2".to_string()), say(&mut bot, "#sala", ".hangman start"));
+    }
+
+    #[test]
+    fn run_with_mock_transport_it_passes() {
+        let mut transport = MockTransport::new(vec![ChatMessage::new("#sala", "ana", "!hangman start"), ChatMessage::new("#sala", "bia", "!hint")]);
+        get_bot().run(&mut transport).unwrap();
+        let expected = vec![
+            ("#sala".to_string(), "New game! _ _ _ | misses: - | [--] 0/2".to_string()),
+            ("#sala".to_string(), "bia used a hint: D. D _ _ | misses: - | [--] 0/2".to_string()),
+        ];
+        assert_eq!(expected, transport.sent());
+    }
+
+    #[test]
+    fn line_transport_it_passes() {
+        assert_eq!(Some(ChatMessage::new("#sala", "ana", "!guess e")), parse_chat_line("#sala ana !guess e"));
+        assert_eq!(None, parse_chat_line("#sala ana"));
+        let mut transport = LineTransport::new("garbage\n#sala ana hi there\n".as_bytes(), Vec::new());
+        assert_eq!(Some(ChatMessage::new("#sala", "ana", "hi there")), transport.receive());
+        assert_eq!(None, transport.receive());
+        transport.send("#sala", "hello").unwrap();
+        let (_, output) = transport.into_inner();
+        assert_eq!("#sala hello\n", String::from_utf8(output).unwrap());
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use jogo_da_forca::cli::cli::{Cli, Command, WordsCommand, EXIT_ERROR, EXIT_FAILURE, EXIT_SUCCESS};
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
+    use jogo_da_forca::cli::commands::{blocklists_of, load_words, load_words_matching, status_code};
+    use jogo_da_forca::config::config::Config;
+    use jogo_da_forca::game::game::GameStatus;
+    use jogo_da_forca::random_word::blocklist::Blocklists;
+    use jogo_da_forca::random_word::index::build_index;
+    use jogo_da_forca::random_word::word_list::{Difficulty, Word, WordFilter};
+
+    #[test]
+    fn parse_without_subcommand_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca"]).unwrap();
+        let expected: bool = true;
+        let actual = cli.command.is_none();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_play_with_options_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "play", "--category", "animal", "--difficulty", "hard", "--lives", "8", "--seed", "42", "--lang", "pt"]).unwrap();
+        let Some(Command::Play(args)) = cli.command else {
+            panic!("expected the play subcommand");
+        };
+        assert_eq!(Some("animal".to_string()), args.category);
+        assert_eq!(Some(Difficulty::Hard), args.difficulty);
+        assert_eq!(Some(8), args.lives);
+        assert_eq!(Some(42), args.seed);
+        assert_eq!(Some("pt".to_string()), args.lang);
+    }
+
+    #[test]
+    fn parse_play_when_lives_is_zero_fails() {
+        let expected: bool = true;
+        let actual = Cli::try_parse_from(["jogo_da_forca", "play", "--lives", "0"]).is_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_replay_when_speed_is_not_a_positive_number_fails() {
+        let expected = vec![true, true, true, true];
+        let actual: Vec<bool> = ["NaN", "inf", "0", "-2"].iter()
+            .map(|speed| Cli::try_parse_from(["jogo_da_forca", "replay", "game.jsonl", "--speed", speed]).is_err())
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_play_when_difficulty_is_unknown_fails() {
+        let expected: bool = true;
+        let actual = Cli::try_parse_from(["jogo_da_forca", "play", "--difficulty", "impossible"]).is_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_global_accessible_flag_after_subcommand_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "daily", "--accessible"]).unwrap();
+        let expected: bool = true;
+        assert_eq!(expected, cli.accessible);
+    }
+
+    #[test]
+    fn parse_daily_with_word_list_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "daily", "--word-list", "words.txt", "--lang", "pt"]).unwrap();
+        let Some(Command::Daily(args)) = cli.command else {
+            panic!("expected the daily subcommand");
+        };
+        let expected = (vec![std::path::PathBuf::from("words.txt")], Some("pt".to_string()));
+        let actual = (args.word_list, args.lang);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_solve_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "solve", "_pp__", "--misses", "xz"]).unwrap();
+        let Some(Command::Solve(args)) = cli.command else {
+            panic!("expected the solve subcommand");
+        };
+        assert_eq!("_pp__", args.pattern);
+        assert_eq!("xz", args.misses);
+    }
+
+    #[test]
+    fn parse_words_validate_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "words", "validate", "words.txt"]).unwrap();
+        let expected: bool = true;
+        let actual = matches!(cli.command, Some(Command::Words(WordsCommand::Validate { .. })));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_words_build_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "words", "build", "a.txt", "b.txt", "-o", "words.idx"]).unwrap();
+        let Some(Command::Words(WordsCommand::Build { paths, output, .. })) = cli.command else {
+            panic!("expected the words build subcommand");
+        };
+        assert_eq!((2, "words.idx".into()), (paths.len(), output));
+    }
+
+    #[test]
+    fn parse_words_validate_rules_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "words", "validate", "words.txt", "--lang", "pt", "--max-length", "12", "--blocklist", "a.txt", "--blocklist", "b.txt"]).unwrap();
+        let Some(Command::Words(WordsCommand::Validate { lang, min_length, max_length, blocklist, .. })) = cli.command else {
+            panic!("expected the words validate subcommand");
+        };
+        assert_eq!(Some("pt".to_string()), lang);
+        assert_eq!((3, 12), (min_length, max_length));
+        assert_eq!(2, blocklist.len());
+    }
+
+    #[test]
+    fn status_code_it_passes() {
+        assert_eq!(EXIT_SUCCESS, status_code(GameStatus::Won));
+        assert_eq!(EXIT_FAILURE, status_code(GameStatus::Lost));
+        assert_eq!(EXIT_ERROR, status_code(GameStatus::InProgress));
+    }
+
+    #[test]
+    fn load_words_when_there_is_no_word_list_uses_built_in_words_it_passes() {
+        let expected: bool = false;
+        let actual = load_words(&[], "en", &Blocklists::family_friendly()).unwrap().is_empty();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn load_words_when_word_list_does_not_exist_uses_built_in_words_it_passes() {
+        let expected = load_words(&[], "en", &Blocklists::default()).unwrap();
+        let actual = load_words(&["/nonexistent/words.txt".into(), "/nonexistent/words.idx".into()], "en", &Blocklists::default()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn load_words_when_one_word_list_does_not_exist_reads_the_others_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_present_words_{}.txt", std::process::id()));
+        write(&path, "apple,fruit
+").unwrap();
+        let loaded = load_words(&["/nonexistent/words.txt".into(), path.clone()], "en", &Blocklists::default());
+        remove_file(&path).unwrap();
+        let expected = vec!["apple"];
+        let actual: Vec<String> = loaded.unwrap().into_iter().map(|word| word.text).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn load_words_leaves_out_blocked_words_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_blocked_words_{}.txt", std::process::id()));
+        write(&path, "apple,fruit\nShit,general\n").unwrap();
+        let paths = vec![path.clone()];
+        let family = load_words(&paths, "en", &blocklists_of(&Config::default()).unwrap());
+        let adult = load_words(&paths, "en", &blocklists_of(&Config { family_friendly: false, ..Config::default() }).unwrap());
+        remove_file(&path).unwrap();
+        let expected = (1, 2);
+        let actual = (family.unwrap().len(), adult.unwrap().len());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn load_words_matching_reads_only_the_language_and_category_asked_for_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_matching_words_{}.idx", std::process::id()));
+        let words = vec![Word::new("gato", "animal", "pt"), Word::new("casa", "geral", "pt"), Word::new("cat", "animal", "en"), Word::new("formiga", "animal", "pt")];
+        write(&path, build_index(&words)).unwrap();
+        let filter = WordFilter { category: Some("animal".to_string()), difficulty: Some(Difficulty::Easy), language: Some("pt".to_string()) };
+        let paths = vec![path.clone()];
+        let loaded = load_words_matching(&paths, &filter, &Blocklists::default(), None);
+        remove_file(&path).unwrap();
+        let expected = vec!["gato", "formiga"];
+        let actual: Vec<String> = loaded.unwrap().into_iter().map(|word| word.text).collect();
+        assert_eq!(expected, actual);
+    }
+}
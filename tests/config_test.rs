@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
+    use std::path::{Path, PathBuf};
+    use jogo_da_forca::config::config::{Config, ConfigError, Theme};
+    use jogo_da_forca::random_word::word_list::Difficulty;
+
+    fn apply(content: &str) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        config.apply_str(content, "config.toml", Path::new("/words"))?;
+        Ok(config)
+    }
+
+    #[test]
+    fn default_config_it_passes() {
+        let config = Config::default();
+        assert_eq!(6, config.lives);
+        assert_eq!("en", config.language());
+        assert_eq!(Theme::Classic, config.theme);
+        assert_eq!(":q", config.keys.quit);
+    }
+
+    #[test]
+    fn apply_str_with_every_key_it_passes() {
+        let content = r#"
+            difficulty = "hard"
+            lives = 8
+            word_lists = ["animals.txt", "/tmp/fruits.txt"]
+            locale = "pt-BR"
+            theme = "compact"
+            sound = true
+            animations = false
+            accessible = true
+
+            [keys]
+            quit = ":sair"
+            hint = ":dica"
+        "#;
+        let config = apply(content).unwrap();
+        assert_eq!(Some(Difficulty::Hard), config.difficulty);
+        assert_eq!(8, config.lives);
+        assert_eq!(vec![PathBuf::from("/words/animals.txt"), PathBuf::from("/tmp/fruits.txt")], config.word_lists);
+        assert_eq!("pt", config.language());
+        assert_eq!(Theme::Compact, config.theme);
+        assert_eq!((true, false, true), (config.sound, config.animations, config.accessible));
+        assert_eq!(":sair", config.keys.quit);
+        assert_eq!(":dica", config.keys.hint);
+    }
+
+    #[test]
+    fn apply_str_when_lives_is_out_of_range_points_at_the_key_it_passes() {
+        let expected = "config.toml: `lives`: expected an integer between 1 and 255";
+        let actual = apply("lives = 0").unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_str_when_key_is_unknown_points_at_the_key_it_passes() {
+        let expected = "config.toml: `colour`: unknown key";
+        let actual = apply("colour = \"red\"").unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_str_when_nested_key_is_invalid_points_at_the_key_it_passes() {
+        let error = apply("[keys]\nquit = \"q\"").unwrap_err();
+        assert_eq!("keys.quit", error.key);
+    }
+
+    #[test]
+    fn apply_str_when_bindings_conflict_fails() {
+        let error = apply("[keys]\nquit = \":x\"\nhint = \":x\"").unwrap_err();
+        assert_eq!("keys.hint", error.key);
+    }
+
+    #[test]
+    fn apply_str_with_player_it_passes() {
+        let expected = "ana".to_string();
+        let actual = apply("player = \" ana \"").unwrap().player_name();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_str_when_player_has_a_comma_fails() {
+        let error = apply("player = \"ana,bia\"").unwrap_err();
+        assert_eq!("player", error.key);
+    }
+
+    #[test]
+    fn apply_str_when_undo_binding_conflicts_fails() {
+        let expected = "config.toml: `keys.undo`: `:q` is already bound to keys.quit";
+        let actual = apply("[keys]\nundo = \":q\"").unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_str_when_theme_is_unknown_fails() {
+        let error = apply("theme = \"neon\"").unwrap_err();
+        assert_eq!("theme", error.key);
+        assert_eq!("unknown theme `neon` (expected classic or compact)", error.message);
+    }
+
+    #[test]
+    fn apply_str_when_locale_is_invalid_fails() {
+        let error = apply("locale = \"portuguese\"").unwrap_err();
+        assert_eq!("locale", error.key);
+    }
+
+    #[test]
+    fn apply_str_when_type_is_wrong_fails() {
+        let error = apply("sound = \"yes\"").unwrap_err();
+        assert_eq!("expected true or false, found string", error.message);
+    }
+
+    #[test]
+    fn apply_str_when_toml_is_invalid_fails() {
+        let expected: bool = true;
+        let actual = apply("lives = ").is_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_env_overrides_files_it_passes() {
+        let mut config = apply("lives = 8\ntheme = \"compact\"").unwrap();
+        let vars = vec![
+            ("JOGO_DA_FORCA_LIVES".to_string(), "3".to_string()),
+            ("JOGO_DA_FORCA_KEYS_HINT".to_string(), ":help".to_string()),
+            ("JOGO_DA_FORCA_WORD_LISTS".to_string(), "a.txt, b.txt".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+        ];
+        config.apply_env(vars, Path::new("/project")).unwrap();
+        assert_eq!(3, config.lives);
+        assert_eq!(Theme::Compact, config.theme);
+        assert_eq!(":help", config.keys.hint);
+        assert_eq!(vec![PathBuf::from("/project/a.txt"), PathBuf::from("/project/b.txt")], config.word_lists);
+    }
+
+    #[test]
+    fn apply_str_blocklists_it_passes() {
+        let config = apply("family_friendly = false\n[blocklists]\nPT = \"pt.txt\"\nen = [\"a.txt\", \"b.txt\"]").unwrap();
+        let expected = vec![
+            ("en".to_string(), vec![PathBuf::from("/words/a.txt"), PathBuf::from("/words/b.txt")]),
+            ("pt".to_string(), vec![PathBuf::from("/words/pt.txt")]),
+        ];
+        let actual: Vec<(String, Vec<PathBuf>)> = config.blocklists.clone().into_iter().collect();
+        assert_eq!(expected, actual);
+        let expected: bool = false;
+        let actual = config.family_friendly;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_str_when_blocklist_language_is_invalid_fails() {
+        let error = apply("[blocklists]\nportuguese = \"pt.txt\"").unwrap_err();
+        assert_eq!("blocklists.portuguese", error.key);
+        assert_eq!("`portuguese` is not a language such as `en` or `pt`", error.message);
+    }
+
+    #[test]
+    fn apply_env_blocklists_it_passes() {
+        let mut config = Config::default();
+        let vars = vec![
+            ("JOGO_DA_FORCA_BLOCKLISTS_PT".to_string(), "a.txt,b.txt".to_string()),
+            ("JOGO_DA_FORCA_FAMILY_FRIENDLY".to_string(), "false".to_string()),
+        ];
+        config.apply_env(vars, Path::new("/project")).unwrap();
+        let expected = (Some(vec![PathBuf::from("/project/a.txt"), PathBuf::from("/project/b.txt")]), false);
+        let actual = (config.blocklists.get("pt").cloned(), config.family_friendly);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_env_when_value_is_invalid_points_at_the_variable_it_passes() {
+        let mut config = Config::default();
+        let vars = vec![("JOGO_DA_FORCA_SOUND".to_string(), "loud".to_string())];
+        let expected = "environment variable JOGO_DA_FORCA_SOUND: expected true or false, found string";
+        let actual = config.apply_env(vars, Path::new(".")).unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_env_when_key_is_unknown_skips_it_it_passes() {
+        let mut config = Config::default();
+        let vars = vec![
+            ("JOGO_DA_FORCA_COLOUR".to_string(), "blue".to_string()),
+            ("JOGO_DA_FORCA_LIVES".to_string(), "4".to_string()),
+        ];
+        let expected = (vec!["JOGO_DA_FORCA_COLOUR".to_string()], 4);
+        let actual = (config.apply_env(vars, Path::new(".")).unwrap(), config.lives);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apply_file_layers_it_passes() {
+        let user = temp_dir().join(format!("jogo_da_forca_user_{}.toml", std::process::id()));
+        let project = temp_dir().join(format!("jogo_da_forca_project_{}.toml", std::process::id()));
+        write(&user, "lives = 8\nsound = true").unwrap();
+        write(&project, "lives = 4").unwrap();
+        let mut config = Config::default();
+        let loaded = (config.apply_file(&user), config.apply_file(&project), config.apply_file(&temp_dir().join("jogo_da_forca_missing.toml")));
+        remove_file(&user).unwrap();
+        remove_file(&project).unwrap();
+        assert_eq!((Ok(true), Ok(true), Ok(false)), loaded);
+        assert_eq!((4, true), (config.lives, config.sound));
+    }
+}
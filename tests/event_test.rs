@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use std::fmt::Arguments;
+    use std::sync::{Arc, Mutex};
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::logger::event::{EventRecord, GameEvent};
+    use jogo_da_forca::logger::json_lines::JsonLinesLogger;
+    use jogo_da_forca::logger::logger::Logger;
+
+    struct SharedLogger(Arc<Mutex<Vec<GameEvent>>>);
+    impl Logger for SharedLogger {
+        fn print(&mut self, _value: Arguments<'_>) {}
+
+        fn log_event(&mut self, record: &EventRecord) {
+            self.0.lock().unwrap().push(record.event.clone());
+        }
+    }
+
+    fn play_script(word: &str, script: &str) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_batch(Input::from_script(script));
+        game.play();
+        game
+    }
+
+    fn get_events(game: &HangmanGame) -> Vec<GameEvent> {
+        game.events().iter().map(|record| record.event.clone()).collect()
+    }
+
+    #[test]
+    fn event_record_to_json_it_passes() {
+        let record = EventRecord { timestamp: 1, event: GameEvent::LetterGuessed { letter: "a".to_string(), hit: true, positions: vec![0, 2] } };
+        let expected = r#"{"timestamp":1,"event":"letter_guessed","letter":"a","hit":true,"positions":[0,2]}"#;
+        let actual = record.to_json();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn event_record_from_json_it_passes() {
+        let expected = EventRecord { timestamp: 5, event: GameEvent::GameLost { attempts: 6, hints: 1 } };
+        let actual = EventRecord::from_json(&expected.to_json()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn event_record_from_json_without_starting_attempts_it_passes() {
+        let expected = GameEvent::GameStarted { word: "dog".to_string(), lives: 6, attempts: 0 };
+        let actual = EventRecord::from_json(r#"{"timestamp":1,"event":"game_started","word":"dog","lives":6}"#).unwrap().event;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn event_record_from_invalid_json_fails() {
+        let expected: bool = true;
+        let actual = EventRecord::from_json(r#"{"timestamp":1,"event":"unknown"}"#).is_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_emits_events_when_won_it_passes() {
+        let game = play_script("Dog", "x\nd\n:h\ng\n");
+        let expected = vec![
+            GameEvent::GameStarted { word: "Dog".to_string(), lives: 6, attempts: 0 },
+            GameEvent::LetterGuessed { letter: "x".to_string(), hit: false, positions: vec![] },
+            GameEvent::LetterGuessed { letter: "d".to_string(), hit: true, positions: vec![0] },
+            GameEvent::HintUsed { letter: "o".to_string(), positions: vec![1] },
+            GameEvent::LetterGuessed { letter: "g".to_string(), hit: true, positions: vec![2] },
+            GameEvent::GameWon { attempts: 1, hints: 1 },
+        ];
+        assert_eq!(expected, get_events(&game));
+    }
+
+    #[test]
+    fn game_emits_events_when_word_is_guessed_it_passes() {
+        let game = play_script("Dog", "cat\ndog\n");
+        let expected = vec![
+            GameEvent::GameStarted { word: "Dog".to_string(), lives: 6, attempts: 0 },
+            GameEvent::WordGuessed { word: "cat".to_string(), correct: false },
+            GameEvent::WordGuessed { word: "dog".to_string(), correct: true },
+            GameEvent::GameWon { attempts: 1, hints: 0 },
+        ];
+        assert_eq!(expected, get_events(&game));
+    }
+
+    #[test]
+    fn game_emits_game_lost_once_it_passes() {
+        let game = play_script("Dog", "q\nw\nr\nt\ny\nu\n");
+        let expected = Some(&GameEvent::GameLost { attempts: 6, hints: 0 });
+        let events = get_events(&game);
+        assert_eq!(expected, events.last());
+        assert_eq!(1, events.iter().filter(|event| matches!(event, GameEvent::GameLost { .. })).count());
+    }
+
+    #[test]
+    fn game_sends_events_to_sinks_it_passes() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut game: HangmanGame = Game::new("Dog", Vec::new(), Vec::new(), Vec::new(), 0);
+        game.add_event_sink(Box::new(SharedLogger(received.clone())));
+        game.set_batch(Input::from_script("dog"));
+        game.play();
+        assert_eq!(get_events(&game), *received.lock().unwrap());
+    }
+
+    #[test]
+    fn json_lines_logger_writes_one_line_per_event_it_passes() {
+        let mut logger = JsonLinesLogger::new(Vec::new());
+        logger.log_event(&EventRecord { timestamp: 1, event: GameEvent::GameWon { attempts: 0, hints: 0 } });
+        logger.log_event(&EventRecord { timestamp: 2, event: GameEvent::GameLost { attempts: 6, hints: 0 } });
+        logger.print(format_args!("ignored"));
+        let expected = "{\"timestamp\":1,\"event\":\"game_won\",\"attempts\":0,\"hints\":0}\n{\"timestamp\":2,\"event\":\"game_lost\",\"attempts\":6,\"hints\":0}\n";
+        let actual = String::from_utf8(logger.into_inner()).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
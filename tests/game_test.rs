@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use std::fmt::Arguments;
-    use jogo_da_forca::{word_contains_letter, get_occurrences, get_blank_chars, print_sequence, print_gibbet, print_head, print_head_and_right_arm, print_head_right_arm_and_left_arm, print_head_right_arm_left_arm_and_stem, print_head_right_arm_left_arm_stem_and_right_leg, print_head_right_arm_left_arm_stem_right_leg_and_left_leg, print_default, print_gibbet_status, win, lost, end, prompt};
+    use jogo_da_forca::{word_contains_letter, get_occurrences, get_blank_chars, print_sequence, print_gibbet, print_head, print_head_and_right_arm, print_head_right_arm_and_left_arm, print_head_right_arm_left_arm_and_stem, print_head_right_arm_left_arm_stem_and_right_leg, print_head_right_arm_left_arm_stem_right_leg_and_left_leg, print_default, print_gibbet_status, win, lost, lost_with_lives, gibbet_stage, end, prompt};
     use jogo_da_forca::constants::gibbet_phases::GIBBET;
     // use jogo_da_forca::game::game::{Game, HangmanGame};
     use jogo_da_forca::logger::logger::Logger;
@@ -52,6 +52,14 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn get_occurrences_when_the_letter_is_a_capital_with_an_accent_it_passes() {
+        let letter: String = String::from("á");
+        let expected = vec![0];
+        let actual = get_occurrences(letter, "Águia");
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn get_occurrences_when_is_in_the_second_position_it_passes() {
         let letter: String = String::from("b");
@@ -133,6 +141,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_format)]
     fn print_sequence_when_is_a_sequence_of_three_underscores_seperated_by_space_it_passes() {
         let mut logger = DummyLogger::default();
         let expected = format!("_ _ _ ");
@@ -335,4 +344,39 @@ mod tests {
         assert_eq!(expected_1, logger.0[0]);
         assert_eq!(expected_2, logger.0[1]);
     }
+
+    #[test]
+    fn lost_with_lives_when_lose() {
+        let expected: bool = true;
+        let actual = lost_with_lives(8, 8);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lost_with_lives_when_not_lose() {
+        let expected: bool = false;
+        let actual = lost_with_lives(6, 8);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn gibbet_stage_when_lives_is_six_it_passes() {
+        let expected: u8 = 4;
+        let actual = gibbet_stage(4, 6);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn gibbet_stage_when_lives_is_three_draws_two_parts_per_miss_it_passes() {
+        let expected: u8 = 2;
+        let actual = gibbet_stage(1, 3);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn gibbet_stage_when_lost_is_complete_it_passes() {
+        let expected: u8 = 6;
+        let actual = gibbet_stage(12, 12);
+        assert_eq!(expected, actual);
+    }
 }
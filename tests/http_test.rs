@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use jogo_da_forca::game::game::GameStatus;
+    use jogo_da_forca::logger::sinks::MultiLogger;
+    use jogo_da_forca::random_word::word_list::{Word, WordFilter};
+    use jogo_da_forca::server::http::{serve_http, Api, ApiResponse, GameView, GuessView, FINISHED_TTL, IDLE_TTL};
+    use jogo_da_forca::server::server::{ServerOptions, DEFAULT_TURN_TIMEOUT};
+    use tiny_http::Server;
+
+    fn get_api() -> Api {
+        let words = vec![Word::new("Ab", "test", "en")];
+        Api::new(ServerOptions { words: Arc::new(words), filter: WordFilter::default(), lives: 2, seed: Some(3), turn_timeout: DEFAULT_TURN_TIMEOUT })
+    }
+
+    fn view(response: &ApiResponse) -> GameView {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    fn guess(response: &ApiResponse) -> GuessView {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn create_game_hides_the_word_it_passes() {
+        let mut api = get_api();
+        let response = api.handle("POST", "/games", "");
+        assert_eq!(201, response.status);
+        let expected = GameView { id: 1, word: "__".to_string(), hits: Vec::new(), misses: Vec::new(), lives: 2, lives_left: 2, status: GameStatus::InProgress, answer: None };
+        assert_eq!(expected, view(&response));
+        assert!(!response.body.to_lowercase().contains("ab"));
+    }
+
+    #[test]
+    fn create_game_with_options_it_passes() {
+        let mut api = get_api();
+        assert_eq!(4, view(&api.handle("POST", "/games", r#"{"lives": 4}"#)).lives);
+        assert_eq!(422, api.handle("POST", "/games", r#"{"lang": "pt"}"#).status);
+        assert_eq!(400, api.handle("POST", "/games", r#"{"lives": 0}"#).status);
+        assert_eq!(400, api.handle("POST", "/games", r#"{"colour": "red"}"#).status);
+    }
+
+    #[test]
+    fn guesses_until_won_it_passes() {
+        let mut api = get_api();
+        api.handle("POST", "/games", "");
+        let response = api.handle("POST", "/games/1/guesses", r#"{"letter": "Z"}"#);
+        let expected: (u16, &str, &str, Vec<String>, u8) = (200, "miss", "__", vec!["z".to_string()], 1);
+        let actual = guess(&response);
+        assert_eq!(expected, (response.status, actual.result.as_str(), actual.game.word.as_str(), actual.game.misses, actual.game.lives_left));
+        assert_eq!(422, api.handle("POST", "/games/1/guesses", r#"{"letter": "z"}"#).status);
+        assert_eq!("hit", guess(&api.handle("POST", "/games/1/guesses", r#"{"letter": "a"}"#)).result);
+        let actual = guess(&api.handle("POST", "/games/1/guesses", r#"{"word": "AB"}"#));
+        assert_eq!(("correct", GameStatus::Won, Some("ab".to_string())), (actual.result.as_str(), actual.game.status, actual.game.answer));
+        assert_eq!(409, api.handle("POST", "/games/1/guesses", r#"{"letter": "b"}"#).status);
+    }
+
+    #[test]
+    fn get_game_reveals_the_word_when_lost_it_passes() {
+        let mut api = get_api();
+        api.handle("POST", "/games", "");
+        assert_eq!(None, view(&api.handle("GET", "/games/1", "")).answer);
+        api.handle("POST", "/games/1/guesses", r#"{"word": "xy"}"#);
+        api.handle("POST", "/games/1/guesses", r#"{"letter": "q"}"#);
+        let actual = view(&api.handle("GET", "/games/1", ""));
+        assert_eq!((GameStatus::Lost, Some("ab".to_string())), (actual.status, actual.answer));
+    }
+
+    #[test]
+    fn errors_it_passes() {
+        let mut api = get_api();
+        api.handle("POST", "/games", "");
+        let expected: Vec<u16> = vec![404, 404, 404, 405, 400, 400, 400];
+        let actual: Vec<u16> = [
+            ("GET", "/games/9", ""),
+            ("GET", "/games/abc", ""),
+            ("GET", "/players", ""),
+            ("DELETE", "/games/1", ""),
+            ("POST", "/games/1/guesses", r#"{"letter": "ab"}"#),
+            ("POST", "/games/1/guesses", r#"{"letter": "a", "word": "ab"}"#),
+            ("POST", "/games/1/guesses", "not json"),
+        ].iter().map(|(method, path, body)| api.handle(method, path, body).status).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn evict_drops_finished_then_idle_games_it_passes() {
+        let mut api = get_api();
+        api.handle("POST", "/games", "");
+        api.handle("POST", "/games", "");
+        api.handle("POST", "/games/1/guesses", r#"{"word": "ab"}"#);
+        let now = Instant::now();
+        api.evict(now + FINISHED_TTL + Duration::from_secs(1));
+        let expected = (404, 200);
+        let actual = (api.handle("GET", "/games/1", "").status, api.handle("GET", "/games/2", "").status);
+        assert_eq!(expected, actual);
+        api.evict(now + IDLE_TTL + Duration::from_secs(1));
+        assert!(api.is_empty());
+    }
+
+    #[test]
+    fn create_game_when_there_are_too_many_fails() {
+        let mut api = get_api().with_max_games(2);
+        api.handle("POST", "/games", "");
+        api.handle("POST", "/games", "");
+        let expected = (503, 2);
+        let actual = (api.handle("POST", "/games", "").status, api.len());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn serve_http_over_tcp_it_passes() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve_http(server, &mut get_api(), &mut MultiLogger::new()));
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(b"POST /games HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 201"));
+        assert!(response.contains("Content-Type: application/json"));
+        assert!(response.ends_with(r#"{"id":1,"word":"__","hits":[],"misses":[],"lives":2,"lives_left":2,"status":"in_progress"}"#));
+    }
+}
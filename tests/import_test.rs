@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use jogo_da_forca::random_word::blocklist::Blocklist;
+    use jogo_da_forca::random_word::import::{format_word_list, import_words, parse_frequency, parse_hunspell, parse_plain, Affixes, Entry, Format, ImportFilter};
+    use jogo_da_forca::random_word::validate::ValidationRules;
+
+    const AFFIXES: &str = "SET UTF-8\nSFX S Y 2\nSFX S 0 s [^sxzhy]\nSFX S 0 es [sxzh]\nSFX D N 1\nSFX D y ied [^aeiou]y\nPFX U Y 1\nPFX U 0 un .\n";
+
+    fn texts(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.text.as_str()).collect()
+    }
+
+    #[test]
+    fn format_of_path_it_passes() {
+        let expected = vec![Format::Hunspell, Format::Frequency, Format::Plain];
+        let actual = vec![Format::of(Path::new("pt_BR.DIC")), Format::of(Path::new("counts.csv")), Format::of(Path::new("/usr/share/dict/words"))];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_from_str_when_unknown_fails() {
+        let expected: Result<Format, String> = Err("unknown format `aspell` (expected hunspell, plain or frequency)".to_string());
+        let actual = "aspell".parse::<Format>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_hunspell_without_affixes_drops_the_flags_it_passes() {
+        let entries = parse_hunspell("3\nbox/S\ncry/D\tpo:verb\nhappy\n", None);
+        let expected = vec!["box", "cry", "happy"];
+        let actual = texts(&entries);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_hunspell_expands_the_affixes_it_passes() {
+        let affixes = Affixes::parse(AFFIXES);
+        let entries = parse_hunspell("3\nbox/S\ncry/D\nplay/D\n", Some(&affixes));
+        let expected = vec!["box", "boxes", "cry", "cried", "play"];
+        let actual = texts(&entries);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn affixes_expand_cross_product_it_passes() {
+        let affixes = Affixes::parse(AFFIXES);
+        let expected = vec!["lock", "locks", "unlock", "unlocks"];
+        let actual = affixes.expand("lock", "SU");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn affixes_expand_long_flags_it_passes() {
+        let affixes = Affixes::parse("FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .\n");
+        let expected = vec!["gato", "gatos"];
+        let actual = affixes.expand("gato", "AaZz");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_plain_it_passes() {
+        let expected = vec![Entry::new("Aaron's", None), Entry::new("apple", None)];
+        let actual = parse_plain("Aaron's\n\napple\n");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_frequency_skips_the_header_it_passes() {
+        let expected = vec![Entry::new("the", Some(1000)), Entry::new("casa", Some(42))];
+        let actual = parse_frequency("word,count\nthe,1000\ncasa\t42\nbroken\n");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn import_words_filters_and_sorts_by_frequency_it_passes() {
+        let entries = parse_frequency("dog,20\nhouse,500\nParis,300\nice-cream,90\nelephant,2\ncasa,80\ncasá,95\nrare,5\ndarn,70\n");
+        let filter = ImportFilter { min_frequency: Some(10), ..ImportFilter::new(ValidationRules::for_language("pt")) };
+        let words = import_words(entries, &filter, &Blocklist::parse("darn\n"));
+        let expected = vec![("house", Some(500)), ("casa", Some(95)), ("dog", Some(20))];
+        let actual: Vec<(&str, Option<u64>)> = words.iter().map(|entry| (entry.text.as_str(), entry.frequency)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn import_words_limit_and_lengths_it_passes() {
+        let rules = ValidationRules { min_length: 4, max_length: 6, ..ValidationRules::for_language("en") };
+        let filter = ImportFilter { limit: Some(2), ..ImportFilter::new(rules) };
+        let words = import_words(parse_plain("cat\nhouse\nelephant\ntiger\nzebra\n"), &filter, &Blocklist::default());
+        let expected = vec!["house", "tiger"];
+        let actual = texts(&words);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_word_list_without_frequencies_it_passes() {
+        let expected = "house,animal\n";
+        let actual = format_word_list(&[Entry::new("house", None)], "animal");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_word_list_it_passes() {
+        let expected = "# weighting: log\nhouse,general,5\ndog,general\n";
+        let actual = format_word_list(&[Entry::new("house", Some(5)), Entry::new("dog", None)], "general");
+        assert_eq!(expected, actual);
+    }
+}
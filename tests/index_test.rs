@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
+    use std::path::Path;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use jogo_da_forca::random_word::index::{build_index, is_index, open_index, WordIndex};
+    use jogo_da_forca::random_word::word_list::{choose_word, Difficulty, Word, WordFilter};
+
+    fn words() -> Vec<Word> {
+        vec![
+            Word::new("Elephant", "animal", "en"),
+            Word::new("Gato", "animal", "pt"),
+            Word::new("Dog", "animal", "en").with_weight(2.5),
+            Word::new("Apple", "fruit", "en"),
+            Word::new("Cat", "animal", "en"),
+            Word::new("Cow", "ANIMAL", "en"),
+            Word::new("Maçã", "fruta", "pt"),
+        ]
+    }
+
+    fn texts(words: &[Word]) -> Vec<&str> {
+        words.iter().map(|word| word.text.as_str()).collect()
+    }
+
+    #[test]
+    fn build_index_round_trip_it_passes() {
+        let index = WordIndex::new(build_index(&words())).unwrap();
+        let mut expected = words();
+        expected.sort_by_key(|word| (word.language.clone(), word.category.to_lowercase(), word.text.chars().count()));
+        // Categories differing in case are stored with the spelling seen first.
+        expected.iter_mut().filter(|word| word.category == "ANIMAL").for_each(|word| word.category = "animal".to_string());
+        let actual: Vec<Word> = (0..index.len()).map(|position| index.word(position)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn query_by_language_and_category_it_passes() {
+        let index = WordIndex::new(build_index(&words())).unwrap();
+        let filter = WordFilter { category: Some("ANIMAL".to_string()), language: Some("en".to_string()), ..WordFilter::default() };
+        let expected = vec!["Dog", "Cat", "Cow", "Elephant"];
+        let actual = index.words(&filter, None);
+        assert_eq!(expected, texts(&actual));
+    }
+
+    #[test]
+    fn query_by_difficulty_and_length_it_passes() {
+        let index = WordIndex::new(build_index(&words())).unwrap();
+        let medium = WordFilter { difficulty: Some(Difficulty::Medium), ..WordFilter::default() };
+        let expected = (vec!["Apple"], vec!["Gato", "Maçã"]);
+        let actual = (index.words(&medium, None), index.words(&WordFilter::default(), Some(4)));
+        assert_eq!(expected, (texts(&actual.0), texts(&actual.1)));
+    }
+
+    #[test]
+    fn choose_draws_like_choose_word_it_passes() {
+        let index = WordIndex::new(build_index(&words())).unwrap();
+        let filter = WordFilter { language: Some("pt".to_string()), ..WordFilter::default() };
+        let listed = index.words(&filter, None);
+        let expected = choose_word(&listed, &filter, &mut StdRng::seed_from_u64(5)).cloned();
+        let actual = index.choose(&filter, &mut StdRng::seed_from_u64(5));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn choose_when_nothing_matches_is_none_it_passes() {
+        let index = WordIndex::new(build_index(&words())).unwrap();
+        let filter = WordFilter { category: Some("planet".to_string()), ..WordFilter::default() };
+        let expected: Option<Word> = None;
+        let actual = index.choose(&filter, &mut StdRng::seed_from_u64(5));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn new_when_bytes_are_not_an_index_fails() {
+        let mut truncated = build_index(&words());
+        truncated.pop();
+        let expected = (true, true, true);
+        let actual = (WordIndex::new(b"apple,fruit\n".to_vec()).is_err(), WordIndex::new(truncated).is_err(), WordIndex::new(build_index(&[])).unwrap().is_empty());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn open_index_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_index_{}.idx", std::process::id()));
+        write(&path, build_index(&words())).unwrap();
+        let index = open_index(&path).map(|index| index.words(&WordFilter { language: Some("pt".to_string()), ..WordFilter::default() }, None));
+        remove_file(&path).unwrap();
+        let expected = vec!["Gato", "Maçã"];
+        let actual = index.unwrap();
+        assert_eq!(expected, texts(&actual));
+    }
+
+    #[test]
+    fn is_index_it_passes() {
+        let expected = (true, false);
+        let actual = (is_index(Path::new("words/pt.IDX")), is_index(Path::new("words/pt.txt")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_file;
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::leaderboard::leaderboard::{load_profiles, load_scores, record_score, same_player, standings, update_profile, week_start, Profile, ScoreEntry, Standing, View};
+    use jogo_da_forca::leaderboard::score::{Score, ScoreInput, ScoreRules};
+    use jogo_da_forca::random_word::word_list::Difficulty;
+
+    /// Monday 2024-01-08 00:00 UTC.
+    const MONDAY: u64 = 1_704_672_000;
+
+    fn get_input(won: bool, lives_left: u8, seconds: u64, hints: u8) -> ScoreInput {
+        ScoreInput { difficulty: Difficulty::Medium, won, lives: 6, lives_left, seconds, hints }
+    }
+
+    fn get_entry(timestamp: u64, player: &str, category: &str, won: bool, total: u32) -> ScoreEntry {
+        ScoreEntry::new(timestamp, player, "apple", category, won, &Score { total, ..Score::default() })
+    }
+
+    #[test]
+    fn score_of_won_game_it_passes() {
+        let expected = Score { base: 200, lives_bonus: 80, speed_bonus: 50, hint_penalty: 50, total: 280 };
+        let actual = ScoreRules::default().score(&get_input(true, 4, 60, 1));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_of_lost_game_is_zero_it_passes() {
+        let expected = Score::default();
+        let actual = ScoreRules::default().score(&get_input(false, 0, 10, 0));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_of_slow_game_has_no_speed_bonus_it_passes() {
+        let expected: u32 = 0;
+        let actual = ScoreRules::default().score(&get_input(true, 1, 600, 0)).speed_bonus;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_lives_bonus_is_scaled_to_six_lives_it_passes() {
+        let rules = ScoreRules::default();
+        let expected = vec![120, 120, 60, 0];
+        let actual: Vec<u32> = [(255, 255), (12, 12), (12, 6), (1, 0)].iter()
+            .map(|(lives, lives_left)| rules.score(&ScoreInput { lives: *lives, lives_left: *lives_left, ..get_input(true, 0, 0, 0) }).lives_bonus)
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_hints_never_go_below_zero_it_passes() {
+        let rules = ScoreRules { per_hint: 1000, ..ScoreRules::default() };
+        let expected: u32 = 0;
+        let actual = rules.score(&get_input(true, 6, 0, 3)).total;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_base_by_difficulty_it_passes() {
+        let rules = ScoreRules::default();
+        let expected = (100, 200, 300);
+        let actual = (rules.base(Difficulty::Easy), rules.base(Difficulty::Medium), rules.base(Difficulty::Hard));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_input_of_game_it_passes() {
+        let mut game = HangmanGame::headless("apple", 6);
+        game.verify_answer("z".to_string());
+        game.use_hint();
+        game.guess_word("apple".to_string());
+        let input = ScoreInput::of(&game);
+        let expected = (Difficulty::Medium, true, 5, 1);
+        let actual = (input.difficulty, input.won, input.lives_left, input.hints);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_entry_to_line_and_back_it_passes() {
+        let expected = get_entry(MONDAY, "ana", "fruit", true, 280);
+        let actual = ScoreEntry::from_line(&expected.to_line()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn score_entry_removes_commas_from_names_it_passes() {
+        let expected = "ana maria";
+        let actual = get_entry(MONDAY, "ana,maria", "fruit", true, 1).player;
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn profile_to_line_and_back_it_passes() {
+        let mut expected = Profile::new("ana", MONDAY);
+        expected.add(&get_entry(MONDAY, "ana", "fruit", true, 280));
+        let actual = Profile::from_line(&expected.to_line()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn week_start_is_monday_it_passes() {
+        let expected = MONDAY;
+        let actual = (week_start(MONDAY), week_start(MONDAY + 6 * 86_400 + 3_600));
+        assert_eq!((expected, expected), actual);
+    }
+
+    #[test]
+    fn standings_all_time_it_passes() {
+        let entries = vec![
+            get_entry(MONDAY, "ana", "fruit", true, 100),
+            get_entry(MONDAY, "bia", "fruit", true, 300),
+            get_entry(MONDAY, "ana", "animal", true, 150),
+            get_entry(MONDAY, "bia", "animal", false, 0),
+        ];
+        let expected = vec![
+            Standing { player: "bia".to_string(), games: 2, wins: 1, score: 300 },
+            Standing { player: "ana".to_string(), games: 2, wins: 2, score: 250 },
+        ];
+        let actual = standings(&entries, &View::AllTime, MONDAY);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn standings_weekly_skips_older_games_it_passes() {
+        let entries = vec![get_entry(MONDAY - 1, "ana", "fruit", true, 900), get_entry(MONDAY + 60, "bia", "fruit", true, 100)];
+        let expected = vec!["bia".to_string()];
+        let actual: Vec<String> = standings(&entries, &View::Weekly, MONDAY + 3_600).into_iter().map(|standing| standing.player).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn standings_by_category_it_passes() {
+        let entries = vec![get_entry(MONDAY, "ana", "fruit", true, 100), get_entry(MONDAY, "bia", "animal", true, 300)];
+        let expected = vec!["ana".to_string()];
+        let actual: Vec<String> = standings(&entries, &View::Category("Fruit".to_string()), MONDAY).into_iter().map(|standing| standing.player).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn record_score_and_update_profile_it_passes() {
+        let scores = temp_dir().join(format!("jogo_da_forca_scores_{}.csv", std::process::id()));
+        let profiles = temp_dir().join(format!("jogo_da_forca_profiles_{}.csv", std::process::id()));
+        let entries = vec![get_entry(MONDAY, "ana", "fruit", true, 280), get_entry(MONDAY + 1, "ana", "fruit", false, 0)];
+        for entry in entries.iter() {
+            record_score(&scores, entry).unwrap();
+            update_profile(&profiles, entry).unwrap();
+        }
+        let loaded = load_scores(&scores).unwrap();
+        let profile = load_profiles(&profiles).unwrap();
+        remove_file(&scores).unwrap();
+        remove_file(&profiles).unwrap();
+        let expected = Profile { name: "ana".to_string(), created: MONDAY, games: 2, wins: 1, total_score: 280, best_score: 280 };
+        assert_eq!(entries, loaded);
+        assert_eq!(vec![expected], profile);
+    }
+
+    #[test]
+    fn same_player_ignores_case_it_passes() {
+        let expected = vec![true, true, false];
+        let actual = vec![same_player("Ana", "ana"), same_player(" ANA MARIA ", "ana,maria"), same_player("ana", "bia")];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn standings_and_profiles_ignore_the_case_of_names_it_passes() {
+        let profiles = temp_dir().join(format!("jogo_da_forca_profiles_case_{}.csv", std::process::id()));
+        let entries = vec![get_entry(MONDAY, "Ana", "fruit", true, 100), get_entry(MONDAY + 1, "ana", "fruit", true, 50)];
+        for entry in entries.iter() {
+            update_profile(&profiles, entry).unwrap();
+        }
+        let profile = load_profiles(&profiles).unwrap();
+        remove_file(&profiles).unwrap();
+        let expected = (vec![("Ana".to_string(), 2)], vec![("Ana".to_string(), 150)]);
+        let actual = (
+            profile.into_iter().map(|profile| (profile.name, profile.games)).collect::<Vec<(String, u32)>>(),
+            standings(&entries, &View::AllTime, MONDAY).into_iter().map(|standing| (standing.player, standing.score)).collect::<Vec<(String, u64)>>(),
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn load_scores_when_file_does_not_exist_is_empty_it_passes() {
+        let expected: bool = true;
+        let actual = load_scores(&temp_dir().join("jogo_da_forca_missing_scores.csv")).unwrap().is_empty();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use std::fmt::Arguments;
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::logger::event::{EventRecord, GameEvent};
+    use jogo_da_forca::logger::logger::Logger;
+    use jogo_da_forca::puppet::gibbet::Gibbet;
+    use jogo_da_forca::replay::replay::{build_steps, describe_event, parse_command, parse_event_log, print_step, split_games, ReplayCommand, ReplayControls, STEP_DELAY};
+
+    #[derive(Default)]
+    struct DummyLogger(Vec<String>);
+    impl Logger for DummyLogger {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.push(value.to_string());
+        }
+    }
+
+    fn record(event: GameEvent) -> EventRecord {
+        EventRecord { timestamp: 0, event }
+    }
+
+    fn get_records(script: &str) -> Vec<EventRecord> {
+        let mut game: HangmanGame = Game::new("Dog", Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_batch(Input::from_script(script));
+        game.play();
+        game.events().to_vec()
+    }
+
+    #[test]
+    fn parse_event_log_it_passes() {
+        let content = get_records("d\no\ng").iter().map(|record| record.to_json()).collect::<Vec<String>>().join("\n");
+        let expected = 5;
+        let actual = parse_event_log(&content).unwrap().len();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_event_log_when_line_is_invalid_reports_the_line_it_passes() {
+        let content = format!("{}\n\nnot json", record(GameEvent::GameWon { attempts: 0, hints: 0 }).to_json());
+        let expected: bool = true;
+        let actual = parse_event_log(&content).unwrap_err().starts_with("line 3:");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn split_games_it_passes() {
+        let mut records = get_records("d\no\ng");
+        records.extend(get_records("x\ndog"));
+        let actual: Vec<usize> = split_games(records).iter().map(|game| game.len()).collect();
+        assert_eq!(vec![5, 4], actual);
+    }
+
+    #[test]
+    fn build_steps_reconstructs_every_state_it_passes() {
+        let (word, steps) = build_steps(&get_records("x\nd\n:h\ng")).unwrap();
+        assert_eq!("Dog", word);
+        assert_eq!(6, steps.len());
+        assert_eq!(vec![" ", " ", " "], steps[0].snapshot.formed_word_by_hits);
+        assert_eq!(1, steps[1].snapshot.attempts);
+        assert_eq!(vec!["d", "o", " "], steps[3].snapshot.formed_word_by_hits);
+        assert_eq!(vec!["d", "o", "g"], steps[5].snapshot.formed_word_by_hits);
+        assert_eq!("Game won", steps[5].caption);
+    }
+
+    #[test]
+    fn build_steps_when_log_does_not_start_a_game_fails() {
+        let expected: bool = true;
+        let actual = build_steps(&[record(GameEvent::GameWon { attempts: 0, hints: 0 })]).is_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn build_steps_when_log_disagrees_with_the_game_fails() {
+        let records = vec![
+            record(GameEvent::GameStarted { word: "Dog".to_string(), lives: 6, attempts: 0 }),
+            record(GameEvent::LetterGuessed { letter: "d".to_string(), hit: false, positions: vec![] }),
+        ];
+        let expected = "event 2: the log says d was a miss, but the game disagrees";
+        let actual = build_steps(&records).unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn build_steps_with_timeouts_it_passes() {
+        let records = vec![
+            record(GameEvent::GameStarted { word: "Dog".to_string(), lives: 2, attempts: 0 }),
+            record(GameEvent::GuessTimedOut { attempts: 1 }),
+            record(GameEvent::GuessTimedOut { attempts: 2 }),
+            record(GameEvent::GameLost { attempts: 2, hints: 0 }),
+        ];
+        let (_, steps) = build_steps(&records).unwrap();
+        let expected: Vec<u8> = vec![0, 1, 2, 2];
+        let actual: Vec<u8> = steps.iter().map(|step| step.snapshot.attempts).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn build_steps_of_a_later_survival_word_starts_with_the_lost_lives_it_passes() {
+        let records = vec![
+            record(GameEvent::GameStarted { word: "Dog".to_string(), lives: 6, attempts: 4 }),
+            record(GameEvent::GuessTimedOut { attempts: 5 }),
+        ];
+        let (_, steps) = build_steps(&records).unwrap();
+        let expected: (Vec<u8>, &str) = (vec![4, 5], "Game started: 3 letters, 2 of 6 lives left");
+        let actual: (Vec<u8>, &str) = (steps.iter().map(|step| step.snapshot.attempts).collect(), &steps[0].caption);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn build_steps_with_undo_it_passes() {
+        let records = vec![
+            record(GameEvent::GameStarted { word: "Dog".to_string(), lives: 6, attempts: 0 }),
+            record(GameEvent::LetterGuessed { letter: "x".to_string(), hit: false, positions: vec![] }),
+            record(GameEvent::GuessUndone { attempts: 0 }),
+        ];
+        let (_, steps) = build_steps(&records).unwrap();
+        let expected = (0, Vec::<String>::new(), "Undid the last guess".to_string());
+        let last = steps.last().unwrap();
+        let actual = (last.snapshot.attempts, last.snapshot.errors.clone(), last.caption.clone());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn describe_event_it_passes() {
+        assert_eq!("Guessed X: miss", describe_event(&GameEvent::LetterGuessed { letter: "x".to_string(), hit: false, positions: vec![] }));
+        assert_eq!("Hint revealed O", describe_event(&GameEvent::HintUsed { letter: "o".to_string(), positions: vec![1] }));
+        assert_eq!("Time ran out: lost a life", describe_event(&GameEvent::GuessTimedOut { attempts: 1 }));
+    }
+
+    #[test]
+    fn print_step_uses_the_gibbet_and_hits_layout_it_passes() {
+        let mut logger = DummyLogger::default();
+        let (_, steps) = build_steps(&get_records("x\nd\no\ng")).unwrap();
+        print_step(&steps[2], 2, steps.len(), &mut logger);
+        assert_eq!("\n\t\tJogo da Forca - replay 3/6", logger.0[0]);
+        assert_eq!("x ", logger.0[2]);
+        assert_eq!(Gibbet::Head.to_string(), logger.0[3]);
+        assert_eq!("d     ", logger.0[4]);
+        assert_eq!(" _ _ _", logger.0[5]);
+    }
+
+    #[test]
+    fn parse_command_it_passes() {
+        assert_eq!(Some(ReplayCommand::TogglePause), parse_command(""));
+        assert_eq!(Some(ReplayCommand::Step), parse_command("n"));
+        assert_eq!(Some(ReplayCommand::Back), parse_command("b"));
+        assert_eq!(Some(ReplayCommand::Faster), parse_command("+"));
+        assert_eq!(Some(ReplayCommand::Quit), parse_command("Q"));
+        assert_eq!(None, parse_command("zzz"));
+    }
+
+    #[test]
+    fn replay_controls_it_passes() {
+        let mut controls = ReplayControls::new(false, 1.0);
+        controls.apply(ReplayCommand::Step, 3);
+        controls.apply(ReplayCommand::Step, 3);
+        controls.apply(ReplayCommand::Step, 3);
+        assert_eq!((2, true), (controls.position, controls.paused));
+        controls.apply(ReplayCommand::Back, 3);
+        assert_eq!(1, controls.position);
+        controls.apply(ReplayCommand::Faster, 3);
+        assert_eq!(2.0, controls.speed);
+        let expected: bool = false;
+        assert_eq!(expected, controls.apply(ReplayCommand::Quit, 3));
+    }
+
+    #[test]
+    fn replay_controls_advance_stops_at_the_end_it_passes() {
+        let mut controls = ReplayControls::new(false, 100.0);
+        assert_eq!(8.0, controls.speed);
+        let expected: bool = false;
+        controls.advance(2);
+        assert_eq!(expected, controls.advance(2));
+    }
+
+    #[test]
+    fn replay_controls_when_speed_is_not_finite_plays_at_normal_speed_it_passes() {
+        let expected = vec![1.0, 1.0, 1.0];
+        let actual: Vec<f64> = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter().map(|speed| ReplayControls::new(false, *speed).speed).collect();
+        assert_eq!(expected, actual);
+        assert_eq!(STEP_DELAY, ReplayControls::new(false, f64::NAN).delay());
+    }
+}
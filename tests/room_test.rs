@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{Error, ErrorKind, Result, Write};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+    use jogo_da_forca::random_word::word_list::{Word, WordFilter};
+    use jogo_da_forca::server::lobby::Lobby;
+    use jogo_da_forca::server::room::{Room, RoomMode};
+    use jogo_da_forca::server::server::{Request, ServerOptions, GREETING};
+
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn take_lines(&self) -> Vec<String> {
+            let content = String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap();
+            content.lines().map(|line| line.to_string()).collect()
+        }
+    }
+
+    /// A client that reads a few lines and then stops, so every write times out.
+    struct StalledWriter(usize);
+    impl Write for StalledWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            self.0 = self.0.checked_sub(1).ok_or(Error::new(ErrorKind::TimedOut, "write timed out"))?;
+            Ok(())
+        }
+    }
+
+    fn get_room(mode: RoomMode, now: Instant) -> Room {
+        let mut room = Room::new("sala", mode, "ab", 2, TIMEOUT, now);
+        room.join(1, "ana", now).unwrap();
+        room.join(2, "bia", now).unwrap();
+        room
+    }
+
+    fn guess(letter: &str) -> Request {
+        Request::Guess(letter.to_string())
+    }
+
+    #[test]
+    fn room_mode_from_str_it_passes() {
+        let expected = vec![Ok(RoomMode::Cooperative), Ok(RoomMode::Competitive), Ok(RoomMode::Competitive)];
+        let actual: Vec<std::result::Result<RoomMode, String>> = ["coop", "versus", "Competitive"].iter().map(|mode| mode.parse()).collect();
+        assert_eq!(expected, actual);
+        assert!("solo".parse::<RoomMode>().is_err());
+    }
+
+    #[test]
+    fn join_announces_players_it_passes() {
+        let now = Instant::now();
+        let mut room = Room::new("sala", RoomMode::Cooperative, "ab", 2, TIMEOUT, now);
+        assert_eq!(vec!["EVENT joined ana players=1", "EVENT turn ana"], room.join(1, "ana", now).unwrap());
+        assert_eq!(vec!["EVENT joined bia players=2"], room.join(2, "bia", now).unwrap());
+        assert!(room.join(3, "ana", now).is_err());
+    }
+
+    #[test]
+    fn coop_shares_the_gibbet_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Cooperative, now);
+        let (reply, events) = room.play(1, guess("z"), now).unwrap();
+        assert_eq!("miss state word=__ misses=z lives=1/2", reply);
+        assert_eq!(vec!["EVENT guess ana z miss state word=__ misses=z lives=1/2", "EVENT turn bia"], events);
+        assert_eq!(Err("it is the turn of bia".to_string()), room.play(1, guess("a"), now));
+        assert_eq!(Err("already guessed z".to_string()), room.play(2, guess("z"), now));
+        let (_, events) = room.play(2, guess("a"), now).unwrap();
+        assert_eq!(vec!["EVENT guess bia a hit state word=a_ misses=z lives=1/2", "EVENT turn ana"], events);
+        let (_, events) = room.play(1, guess("y"), now).unwrap();
+        assert_eq!(vec!["EVENT guess ana y miss result lost word=ab"], events);
+        assert!(room.is_over());
+        assert_eq!("result lost word=ab", room.state_of(2));
+    }
+
+    #[test]
+    fn versus_first_to_solve_wins_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Competitive, now);
+        let (reply, _) = room.play(1, guess("a"), now).unwrap();
+        assert_eq!("hit state word=a_ misses= lives=2/2", reply);
+        assert_eq!("state word=__ misses= lives=2/2", room.state_of(2));
+        room.play(2, guess("z"), now).unwrap();
+        let (reply, events) = room.play(1, guess("b"), now).unwrap();
+        assert_eq!("hit result won word=ab", reply);
+        assert_eq!(vec!["EVENT guess ana b hit", "EVENT winner ana word=ab"], events);
+        assert_eq!("result lost word=ab", room.state_of(2));
+        assert!(room.play(2, guess("a"), now).is_err());
+    }
+
+    #[test]
+    fn versus_skips_players_out_of_lives_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Competitive, now);
+        room.play(1, Request::Word("xy".to_string()), now).unwrap();
+        room.play(2, guess("a"), now).unwrap();
+        let (_, events) = room.play(1, Request::Word("yx".to_string()), now).unwrap();
+        assert_eq!(vec!["EVENT word ana yx wrong", "EVENT out ana", "EVENT turn bia"], events);
+        let (_, events) = room.play(2, guess("z"), now).unwrap();
+        assert_eq!(vec!["EVENT guess bia z miss", "EVENT turn bia"], events);
+    }
+
+    #[test]
+    fn tick_skips_idle_players_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Cooperative, now);
+        assert!(room.tick(now + Duration::from_secs(29)).is_empty());
+        let expected = vec!["EVENT timeout ana", "EVENT turn bia"];
+        let actual = room.tick(now + TIMEOUT);
+        assert_eq!(expected, actual);
+        assert_eq!("bia", room.current().unwrap().name);
+    }
+
+    #[test]
+    fn leave_passes_the_turn_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Cooperative, now);
+        assert_eq!(vec!["EVENT left ana", "EVENT turn bia"], room.leave(1, now));
+        assert_eq!(vec![2], room.members());
+    }
+
+    #[test]
+    fn restart_only_when_over_it_passes() {
+        let now = Instant::now();
+        let mut room = get_room(RoomMode::Cooperative, now);
+        assert!(room.restart("cd", now).is_err());
+        room.play(1, Request::Word("ab".to_string()), now).unwrap();
+        let expected = vec!["EVENT new state word=__ misses= lives=2/2", "EVENT turn ana"];
+        let actual = room.restart("cd", now).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lobby_broadcasts_to_the_room_it_passes() {
+        let words = vec![Word::new("ab", "test", "en")];
+        let mut lobby = Lobby::new(ServerOptions { words: Arc::new(words), filter: WordFilter::default(), lives: 2, seed: Some(1), turn_timeout: TIMEOUT });
+        let (ana, bia) = (SharedBuffer::default(), SharedBuffer::default());
+        let now = Instant::now();
+        lobby.connect(1, Box::new(ana.clone()));
+        lobby.connect(2, Box::new(bia.clone()));
+        for (id, line) in [(1, "NAME ana"), (2, "NAME bia"), (1, "JOIN sala"), (2, "JOIN sala versus"), (2, "JOIN sala coop"), (2, "GUESS a"), (1, "GUESS a")] {
+            lobby.dispatch(id, line, now);
+        }
+        let expected = vec![GREETING, "OK name ana", "OK joined sala coop state word=__ misses= lives=2/2", "EVENT joined ana players=1", "EVENT turn ana", "EVENT joined bia players=2", "OK hit state word=a_ misses= lives=2/2", "EVENT guess ana a hit state word=a_ misses= lives=2/2", "EVENT turn bia"];
+        assert_eq!(expected, ana.take_lines());
+        let expected = vec![GREETING, "OK name bia", "ERR room sala is a coop room", "OK joined sala coop state word=__ misses= lives=2/2", "EVENT joined bia players=2", "ERR it is the turn of ana", "EVENT guess ana a hit state word=a_ misses= lives=2/2", "EVENT turn bia"];
+        assert_eq!(expected, bia.take_lines());
+        lobby.tick(now + TIMEOUT);
+        assert_eq!(vec!["EVENT timeout bia", "EVENT turn ana"], bia.take_lines());
+        assert!(!lobby.dispatch(1, "QUIT", now));
+        assert_eq!(vec!["EVENT left ana", "EVENT turn bia"], bia.take_lines());
+    }
+
+    #[test]
+    fn lobby_drops_a_client_that_stops_reading_it_passes() {
+        let words = vec![Word::new("ab", "test", "en")];
+        let mut lobby = Lobby::new(ServerOptions { words: Arc::new(words), filter: WordFilter::default(), lives: 2, seed: Some(1), turn_timeout: TIMEOUT });
+        let ana = SharedBuffer::default();
+        let now = Instant::now();
+        lobby.connect(1, Box::new(ana.clone()));
+        lobby.connect(2, Box::new(StalledWriter(3)));
+        for (id, line) in [(1, "NAME ana"), (1, "JOIN sala"), (2, "NAME bia")] {
+            lobby.dispatch(id, line, now);
+        }
+        ana.take_lines();
+        let expected = (false, vec!["EVENT joined bia players=2".to_string(), "EVENT left bia".to_string()]);
+        let actual = (lobby.dispatch(2, "JOIN sala", now), ana.take_lines());
+        assert_eq!(expected, actual);
+        let expected = (false, 1);
+        let actual = (lobby.dispatch(2, "STATE", now), lobby.rooms()[0].members().len());
+        assert_eq!(expected, actual);
+    }
+}
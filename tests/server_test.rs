@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::Arc;
+    use std::thread;
+    use jogo_da_forca::logger::sinks::MultiLogger;
+    use jogo_da_forca::random_word::word_list::{Word, WordFilter};
+    use jogo_da_forca::server::room::RoomMode;
+    use jogo_da_forca::server::server::{parse_request, serve, Request, ServerOptions, Session, DEFAULT_TURN_TIMEOUT, GREETING};
+
+    fn get_options(words: &[&str]) -> ServerOptions {
+        let words = words.iter().map(|word| Word::new(word, "test", "en")).collect();
+        ServerOptions { words: Arc::new(words), filter: WordFilter::default(), lives: 2, seed: Some(7), turn_timeout: DEFAULT_TURN_TIMEOUT }
+    }
+
+    #[test]
+    fn parse_request_it_passes() {
+        let expected = vec![Ok(Request::New), Ok(Request::Guess("a".to_string())), Ok(Request::Word("apple".to_string())), Ok(Request::State), Ok(Request::Quit)];
+        let actual: Vec<Result<Request, String>> = ["NEW", "guess A", "WORD Apple", " state ", "quit"].iter().map(|line| parse_request(line)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_room_requests_it_passes() {
+        let expected = vec![Ok(Request::Name("ana".to_string())), Ok(Request::Join("sala".to_string(), None)), Ok(Request::Join("sala".to_string(), Some(RoomMode::Competitive))), Ok(Request::Leave)];
+        let actual: Vec<Result<Request, String>> = ["NAME ana", "JOIN sala", "join sala versus", "LEAVE"].iter().map(|line| parse_request(line)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_request_errors_it_passes() {
+        let expected: Vec<bool> = vec![true, true, true, true, true, true, true];
+        let actual: Vec<bool> = ["GUESS", "GUESS ab", "GUESS 1", "WORD", "JUMP", "JOIN sala solo", "NAME ana bia"].iter().map(|line| parse_request(line).is_err()).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn session_requires_a_game_it_passes() {
+        let mut session = Session::new(get_options(&["ab"]));
+        let expected = "ERR no game in progress, send NEW first";
+        let actual = session.reply("GUESS a");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn session_plays_a_game_it_passes() {
+        let mut session = Session::new(get_options(&["ab"]));
+        let expected = vec![
+            "OK state word=__ misses= lives=2/2",
+            "OK hit state word=a_ misses= lives=2/2",
+            "ERR already guessed a",
+            "OK miss state word=a_ misses=z lives=1/2",
+            "OK hit result won word=ab",
+            "ERR the game is over, send NEW to play again",
+            "OK result won word=ab",
+        ];
+        let actual: Vec<String> = ["NEW", "GUESS a", "GUESS a", "GUESS z", "GUESS b", "GUESS c", "STATE"].iter().map(|line| session.reply(line)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn session_word_guess_it_passes() {
+        let mut session = Session::new(get_options(&["ab"]));
+        session.reply("NEW");
+        let expected = vec!["OK wrong state word=__ misses=ba lives=1/2", "OK wrong result lost word=ab"];
+        let actual: Vec<String> = ["WORD ba", "WORD xy"].iter().map(|line| session.reply(line)).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn serve_over_tcp_it_passes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, get_options(&["ab"]), &mut MultiLogger::new()));
+        let stream = TcpStream::connect(address).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        writer.write_all(b"NEW\nGUESS a\nWORD ab\nQUIT\n").unwrap();
+        let actual: Vec<String> = (&mut reader).lines().map(|line| line.unwrap()).collect();
+        let expected = vec![GREETING, "OK state word=__ misses= lives=2/2", "OK hit state word=a_ misses= lives=2/2", "OK correct result won word=ab", "OK bye"];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn serve_when_a_client_sends_invalid_utf8_it_leaves_the_room_it_passes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, get_options(&["ab"]), &mut MultiLogger::new()));
+        let ana = TcpStream::connect(address).unwrap();
+        let mut reader = BufReader::new(ana.try_clone().unwrap());
+        let mut lines = (&mut reader).lines().map(|line| line.unwrap());
+        (&ana).write_all(b"NAME ana\nJOIN sala versus\n").unwrap();
+        let mut bia = TcpStream::connect(address).unwrap();
+        bia.write_all(b"NAME bia\nJOIN sala versus\n").unwrap();
+        assert!(lines.any(|line| line == "EVENT joined bia players=2"));
+        bia.write_all(b"GUESS \xff\xfe\n").unwrap();
+        let expected = Some("EVENT left bia".to_string());
+        let actual = lines.find(|line| line.starts_with("EVENT left"));
+        assert_eq!(expected, actual);
+    }
+}
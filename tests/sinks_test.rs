@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::cli::cli::Cli;
+    use jogo_da_forca::game::game::{Game, HangmanGame};
+    use jogo_da_forca::logger::logger::{Level, Logger};
+    use jogo_da_forca::logger::sinks::{FileLogger, LevelFilter, MemoryLogger, MultiLogger};
+
+    fn play_script(word: &str, script: &str, logger: &MemoryLogger) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_batch(Input::from_script(script));
+        game.set_logger(Box::new(logger.clone()));
+        game.play();
+        game
+    }
+
+    #[test]
+    fn level_from_str_it_passes() {
+        let expected = vec![Ok(Level::Debug), Ok(Level::Warn), Ok(Level::Error)];
+        let actual: Vec<Result<Level, String>> = ["debug", "WARNING", "error"].iter().map(|level| level.parse()).collect();
+        assert_eq!(expected, actual);
+        assert!("verbose".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn level_order_it_passes() {
+        assert!(Level::Debug < Level::Info);
+        assert!(Level::Warn < Level::Error);
+    }
+
+    #[test]
+    fn memory_logger_records_levels_it_passes() {
+        let memory = MemoryLogger::new();
+        let mut logger = memory.clone();
+        logger.print(format_args!("hello"));
+        logger.log(Level::Debug, format_args!("details"));
+        let expected = vec![(Level::Info, "hello".to_string()), (Level::Debug, "details".to_string())];
+        let actual = memory.lines();
+        assert_eq!(expected, actual);
+        assert_eq!(vec!["hello".to_string()], memory.messages(Level::Info));
+    }
+
+    #[test]
+    fn level_filter_drops_lower_levels_it_passes() {
+        let memory = MemoryLogger::new();
+        let mut logger = LevelFilter::new(Level::Warn, Box::new(memory.clone()));
+        logger.print(format_args!("info"));
+        logger.log(Level::Debug, format_args!("debug"));
+        logger.log(Level::Error, format_args!("error"));
+        let expected = vec![(Level::Error, "error".to_string())];
+        let actual = memory.lines();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn multi_logger_fans_out_it_passes() {
+        let first = MemoryLogger::new();
+        let second = MemoryLogger::new();
+        let mut logger = MultiLogger::new()
+            .with(Box::new(first.clone()))
+            .with(Box::new(LevelFilter::new(Level::Info, Box::new(second.clone()))));
+        logger.log(Level::Debug, format_args!("debug"));
+        logger.print(format_args!("info"));
+        assert_eq!(2, logger.len());
+        assert_eq!(vec!["debug".to_string(), "info".to_string()], first.messages(Level::Debug));
+        assert_eq!(vec!["info".to_string()], second.messages(Level::Debug));
+    }
+
+    #[test]
+    fn file_logger_prefixes_levels_it_passes() {
+        let mut logger = FileLogger::new(Vec::new());
+        logger.print(format_args!("You WON"));
+        logger.log(Level::Debug, format_args!("letter guess a"));
+        let expected = "[info] You WON\n[debug] letter guess a\n";
+        let actual = String::from_utf8(logger.into_inner()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_writes_to_injected_logger_it_passes() {
+        let memory = MemoryLogger::new();
+        play_script("ab", "a\nb\n", &memory);
+        let expected = vec!["state word=__ misses= lives=6/6", "guess a hit", "state word=a_ misses= lives=6/6", "guess b hit", "result won word=ab"];
+        let actual = memory.messages(Level::Info);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_debug_diagnostics_it_passes() {
+        let memory = MemoryLogger::new();
+        play_script("ab", "z\n", &memory);
+        let expected = vec!["game started: word=ab lives=6", "letter guess z hit=false positions=[]", "input closed"];
+        let actual = memory.messages(Level::Debug).into_iter()
+            .filter(|line| !line.starts_with("state") && !line.starts_with("guess") && !line.starts_with("result"))
+            .collect::<Vec<String>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_log_options_it_passes() {
+        let cli = Cli::try_parse_from(["jogo_da_forca", "play", "--log-file", "game.log", "--log-level", "debug"]).unwrap();
+        assert_eq!(Some("game.log".into()), cli.log_file);
+        assert_eq!(Level::Debug, cli.log_level);
+    }
+}
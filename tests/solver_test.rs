@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use std::fmt::Arguments;
+    use jogo_da_forca::logger::logger::Logger;
+    use jogo_da_forca::random_word::word_list::{parse_word_list, Word};
+    use jogo_da_forca::solver::solver::{candidates, matches_pattern, print_solution, suggest_letter};
+
+    #[derive(Default)]
+    struct DummyLogger(Vec<String>);
+    impl Logger for DummyLogger {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.push(value.to_string());
+        }
+    }
+
+    fn get_words() -> Vec<Word> {
+        parse_word_list("apple\napply\nample\nangle\nDog", "en")
+    }
+
+    #[test]
+    fn matches_pattern_it_passes() {
+        let expected: bool = true;
+        let actual = matches_pattern("Apple", "_pp__", "");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn matches_pattern_when_revealed_letter_is_hidden_fails() {
+        let expected: bool = false;
+        let actual = matches_pattern("Apple", "ap___", "");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn matches_pattern_when_word_has_a_miss_fails() {
+        let expected: bool = false;
+        let actual = matches_pattern("Apple", "a____", "e");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn candidates_it_passes() {
+        let words = get_words();
+        let expected = vec!["apple", "apply"];
+        let actual: Vec<&str> = candidates(&words, "app__", "").iter().map(|word| word.text.as_str()).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn suggest_letter_it_passes() {
+        let words = get_words();
+        let found = candidates(&words, "a____", "");
+        let expected = Some('l');
+        let actual = suggest_letter(&found, "a____", "");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn print_solution_it_passes() {
+        let mut logger = DummyLogger::default();
+        print_solution(&get_words(), "_o_", "", &mut logger);
+        assert_eq!(vec!["1 candidate(s)", "dog", "Suggested letter: d"], logger.0);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_file;
+    use jogo_da_forca::stats::stats::{load_records, record_game, GameRecord, Summary};
+
+    fn get_record(won: bool) -> GameRecord {
+        GameRecord { timestamp: 1, word: "Apple".to_string(), won, attempts: 2, lives: 6, undos: 0 }
+    }
+
+    #[test]
+    fn game_record_to_line_and_back_it_passes() {
+        let expected = get_record(true);
+        let actual = GameRecord::from_line(&expected.to_line()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_record_from_invalid_line_is_none_it_passes() {
+        let expected: bool = true;
+        let actual = GameRecord::from_line("not,a,record").is_none();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn summary_from_records_it_passes() {
+        let records = vec![get_record(true), get_record(true), get_record(false), get_record(true)];
+        let summary = Summary::from_records(&records);
+        assert_eq!(4, summary.played);
+        assert_eq!(3, summary.won);
+        assert_eq!(1, summary.lost);
+        assert_eq!(1, summary.current_streak);
+        assert_eq!(2, summary.best_streak);
+        assert_eq!(75.0, summary.win_rate());
+    }
+
+    #[test]
+    fn summary_win_rate_when_no_games_it_passes() {
+        let expected: f64 = 0.0;
+        let actual = Summary::default().win_rate();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn record_game_and_load_records_it_passes() {
+        let path = temp_dir().join(format!("jogo_da_forca_stats_{}.csv", std::process::id()));
+        record_game(&path, &get_record(true)).unwrap();
+        record_game(&path, &get_record(false)).unwrap();
+        let actual = load_records(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(vec![get_record(true), get_record(false)], actual);
+    }
+
+    #[test]
+    fn load_records_when_file_does_not_exist_is_empty_it_passes() {
+        let expected: bool = true;
+        let actual = load_records(&temp_dir().join("jogo_da_forca_missing_stats.csv")).unwrap().is_empty();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_record_from_line_without_undos_it_passes() {
+        let expected = get_record(true);
+        let actual = GameRecord::from_line("1,Apple,won,2,6").unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn game_record_with_many_undos_to_line_and_back_it_passes() {
+        let expected = get_record(true).with_undos(300);
+        let actual = GameRecord::from_line(&expected.to_line()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn summary_leaves_out_games_with_undos_it_passes() {
+        let records = vec![get_record(true), get_record(false).with_undos(2), get_record(true)];
+        let summary = Summary::from_records(&records);
+        let expected = (2, 2, 0, 2, 1);
+        let actual = (summary.played, summary.won, summary.lost, summary.current_streak, summary.practice);
+        assert_eq!(expected, actual);
+    }
+}
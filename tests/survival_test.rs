@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::game::game::{Game, GameStatus, HangmanGame};
+    use jogo_da_forca::logger::event::GameEvent;
+    use jogo_da_forca::logger::sinks::MultiLogger;
+    use jogo_da_forca::random_word::word_list::{Difficulty, Word, WordFilter};
+    use jogo_da_forca::survival::survival::SurvivalRun;
+
+    fn get_words() -> Vec<Word> {
+        vec![Word::new("cat", "animal", "en"), Word::new("horse", "animal", "en"), Word::new("elephant", "animal", "en")]
+    }
+
+    fn play(run: &SurvivalRun, word: &str, script: &str) -> HangmanGame {
+        let mut game = run.new_game(word);
+        game.set_batch(Input::from_script(script));
+        game.set_logger(Box::new(MultiLogger::new()));
+        game.play();
+        game
+    }
+
+    #[test]
+    fn new_run_it_passes() {
+        let run = SurvivalRun::new(6);
+        let expected = (6, 6, 0, 1, false, Difficulty::Easy);
+        let actual = (run.lives(), run.max_lives(), run.solved(), run.round(), run.is_over(), run.difficulty());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn misses_carry_over_and_solving_refunds_a_life_it_passes() {
+        let mut run = SurvivalRun::new(6);
+        let game = play(&run, "cat", "x\ny\nz\nc\na\nt");
+        run.finish_word(&game, 100);
+        let expected = (4, 1, 100);
+        let actual = (run.lives(), run.solved(), run.score());
+        assert_eq!(expected, actual);
+        let game = run.new_game("dog");
+        assert_eq!((2, 6, 2), (game.attempts(), game.lives(), game.stage()));
+        let game = play(&run, "dog", "d\no\ng");
+        let expected = Some(&GameEvent::GameStarted { word: "dog".to_string(), lives: 6, attempts: 2 });
+        let actual = game.events().first().map(|record| &record.event);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn refund_never_goes_over_the_starting_lives_it_passes() {
+        let mut run = SurvivalRun::new(3);
+        run.finish_word(&play(&run, "cat", "c\na\nt"), 100);
+        let expected: u8 = 3;
+        let actual = run.lives();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn losing_a_word_ends_the_run_it_passes() {
+        let mut run = SurvivalRun::new(2);
+        let game = play(&run, "cat", "x\ny");
+        run.finish_word(&game, 0);
+        let expected = (GameStatus::Lost, true, 0);
+        let actual = (game.status(), run.is_over(), run.score());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn difficulty_ramps_up_it_passes() {
+        let mut run = SurvivalRun::new(6).with_ramp(1);
+        let mut difficulties = vec![run.difficulty()];
+        for _ in 0..2 {
+            run.finish_word(&play(&run, "cat", "c\na\nt"), 100);
+            difficulties.push(run.difficulty());
+        }
+        let expected = vec![Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+        assert_eq!(expected, difficulties);
+    }
+
+    #[test]
+    fn choose_picks_the_current_difficulty_without_repeating_it_passes() {
+        let words = get_words();
+        let mut run = SurvivalRun::new(6);
+        let mut rng = StdRng::seed_from_u64(1);
+        let first = run.choose(&words, &WordFilter::default(), &mut rng).map(|word| word.text.clone());
+        let second = run.choose(&words, &WordFilter::default(), &mut rng).map(|word| word.text.clone());
+        assert_eq!(Some("cat".to_string()), first);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn choose_repeats_words_once_the_list_is_exhausted_it_passes() {
+        let words = vec![Word::new("cat", "animal", "en")];
+        let mut run = SurvivalRun::new(6);
+        let mut rng = StdRng::seed_from_u64(1);
+        run.choose(&words, &WordFilter::default(), &mut rng);
+        let expected = Some("cat".to_string());
+        let actual = run.choose(&words, &WordFilter::default(), &mut rng).map(|word| word.text.clone());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn new_game_when_run_is_fresh_has_no_attempts_it_passes() {
+        let run = SurvivalRun::new(6);
+        let expected: (u8, u8) = (0, 6);
+        let game: HangmanGame = run.new_game("cat");
+        let actual = (game.attempts(), game.lives());
+        assert_eq!(expected, actual);
+    }
+}
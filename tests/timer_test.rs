@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::fmt::Arguments;
+    use std::io::{BufReader, Cursor, Read};
+    use std::sync::{Arc, Mutex};
+    use std::thread::sleep;
+    use std::time::Duration;
+    use jogo_da_forca::batch::batch::Input;
+    use jogo_da_forca::game::game::{Game, GameStatus, HangmanGame};
+    use jogo_da_forca::logger::event::{now_millis, GameEvent};
+    use jogo_da_forca::logger::logger::{Level, Logger};
+    use jogo_da_forca::logger::sinks::MemoryLogger;
+    use jogo_da_forca::timer::timer::{format_clock, format_time_left, BlitzTally, Countdown, LineReader, Poll};
+
+    /// A player who never types anything.
+    struct Stalled;
+    impl Read for Stalled {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            sleep(Duration::from_secs(3600));
+            Ok(0)
+        }
+    }
+
+    /// A player who keeps pressing enter without typing a guess.
+    struct Fidgety;
+    impl Read for Fidgety {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            sleep(Duration::from_millis(5));
+            buf[0] = b'\n';
+            Ok(1)
+        }
+    }
+
+    /// A terminal that tells lines printed from lines rewritten in place.
+    #[derive(Clone, Default)]
+    struct Screen(Arc<Mutex<Vec<String>>>);
+    impl Logger for Screen {
+        fn print(&mut self, value: Arguments<'_>) {
+            self.0.lock().unwrap().push(value.to_string());
+        }
+
+        fn reprint(&mut self, lines_up: u16, value: Arguments<'_>) {
+            self.0.lock().unwrap().push(format!("{lines_up} up: {value}"));
+        }
+    }
+
+    fn get_game(word: &str, lives: u8, input: Input, logger: &MemoryLogger) -> HangmanGame {
+        let mut game: HangmanGame = Game::new(word, Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_lives(lives);
+        game.set_batch(input);
+        game.set_logger(Box::new(logger.clone()));
+        game
+    }
+
+    #[test]
+    fn countdown_it_passes() {
+        let mut countdown = Countdown::new(Duration::from_secs(10), 1_000);
+        let expected = (10_000, 4_000, 0, false, true);
+        let actual = (countdown.remaining(1_000), countdown.remaining(7_000), countdown.remaining(12_000), countdown.is_expired(10_999), countdown.is_expired(11_000));
+        assert_eq!(expected, actual);
+        countdown.restart(11_000);
+        assert_eq!(10_000, countdown.remaining(11_000));
+    }
+
+    #[test]
+    fn countdown_when_the_limit_is_huge_does_not_overflow_it_passes() {
+        let guess = Countdown::new(Duration::from_secs(18_446_744_073_709_551), 1_000);
+        let longest = Countdown::new(Duration::MAX, 1_000);
+        let expected = (u64::MAX - 1_000, u64::MAX - 1_000, false);
+        let actual = (guess.remaining(1_000), longest.remaining(1_000), longest.is_expired(u64::MAX - 1));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_clock_rounds_up_it_passes() {
+        let expected = ("0:00", "0:01", "0:10", "1:23");
+        let actual = (format_clock(0), format_clock(1), format_clock(9_001), format_clock(83_000));
+        assert_eq!(expected, (actual.0.as_str(), actual.1.as_str(), actual.2.as_str(), actual.3.as_str()));
+    }
+
+    #[test]
+    fn format_time_left_it_passes() {
+        assert_eq!("Time left: 0:09 to guess", format_time_left(Some(9_000), None));
+        assert_eq!("Time left: 1:23 in the blitz", format_time_left(None, Some(83_000)));
+        assert_eq!("Time left: 0:09 to guess, 1:23 in the blitz", format_time_left(Some(9_000), Some(83_000)));
+    }
+
+    #[test]
+    fn blitz_tally_it_passes() {
+        let mut tally = BlitzTally::default();
+        tally.add(true, 300);
+        tally.add(false, 0);
+        let expected = BlitzTally { played: 2, solved: 1, score: 300 };
+        assert_eq!(expected, tally);
+    }
+
+    #[test]
+    fn line_reader_spawn_reads_every_line_then_closes_it_passes() {
+        let reader = LineReader::spawn(Cursor::new("a\nb\n"), false);
+        let expected = vec![Poll::Line("a".to_string()), Poll::Line("b".to_string()), Poll::Closed];
+        let actual: Vec<Poll> = (0..3).map(|_| reader.poll(Duration::from_secs(5))).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn line_reader_when_nothing_is_typed_is_pending_it_passes() {
+        let reader = LineReader::spawn(BufReader::new(Stalled), true);
+        let expected = (Poll::Pending, true);
+        let actual = (reader.poll(Duration::from_millis(20)), reader.is_interactive());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn line_reader_clones_share_the_lines_it_passes() {
+        let reader = LineReader::from_lines(VecDeque::from(vec!["a".to_string(), "b".to_string()]));
+        let clone = reader.clone();
+        let expected = (Poll::Line("a".to_string()), Poll::Line("b".to_string()), true);
+        let actual = (reader.poll(Duration::ZERO), clone.poll(Duration::ZERO), reader == clone);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn time_out_costs_a_life_it_passes() {
+        let mut game = HangmanGame::headless("dog", 2);
+        game.time_out();
+        let expected = (1, GameStatus::InProgress, Some(&GameEvent::GuessTimedOut { attempts: 1 }));
+        let actual = (game.attempts(), game.status(), game.events().last().map(|record| &record.event));
+        assert_eq!(expected, actual);
+        game.time_out();
+        assert_eq!(GameStatus::Lost, game.status());
+    }
+
+    #[test]
+    fn play_when_guesses_are_too_slow_is_lost_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut game = get_game("dog", 2, Input::Timed(LineReader::spawn(BufReader::new(Stalled), false)), &logger);
+        game.set_guess_time(Some(Duration::from_millis(30)));
+        game.play();
+        let expected = vec!["state word=___ misses= lives=2/2", "timeout", "state word=___ misses= lives=1/2", "timeout", "result lost word=dog"];
+        assert_eq!(expected, logger.messages(Level::Info));
+        assert_eq!(GameStatus::Lost, game.status());
+    }
+
+    #[test]
+    fn play_when_only_empty_lines_are_typed_times_out_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut game = get_game("dog", 2, Input::Timed(LineReader::spawn(BufReader::new(Fidgety), false)), &logger);
+        game.set_guess_time(Some(Duration::from_millis(60)));
+        game.play();
+        let expected = (GameStatus::Lost, 2);
+        let actual = (game.status(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_when_the_clock_ticks_only_rewrites_the_clock_line_it_passes() {
+        let screen = Screen::default();
+        let mut game: HangmanGame = Game::new("dog", Vec::new(), Vec::new(), Vec::new(), 0);
+        game.set_lives(1);
+        game.set_batch(Input::Timed(LineReader::spawn(BufReader::new(Stalled), true)));
+        game.set_logger(Box::new(screen.clone()));
+        game.set_guess_time(Some(Duration::from_millis(1_200)));
+        game.play();
+        let lines = screen.0.lock().unwrap().clone();
+        let waiting: Vec<&String> = lines.iter().skip_while(|line| !line.starts_with("Time left")).take_while(|line| !line.starts_with("Time is up")).collect();
+        let expected = (vec!["Time left: 0:02 to guess", "Choose a letter: ", "2 up: Time left: 0:01 to guess"], 1);
+        let actual = (waiting.iter().take(3).map(|line| line.as_str()).collect::<Vec<&str>>(), lines.iter().filter(|line| line.contains("Jogo da Forca")).count());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_when_guesses_are_in_time_is_won_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut game = get_game("dog", 2, Input::from_script("d\no\ng"), &logger);
+        game.set_guess_time(Some(Duration::from_millis(30)));
+        game.play();
+        let expected = (GameStatus::Won, 0);
+        let actual = (game.status(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn play_when_deadline_passes_is_lost_and_out_of_time_it_passes() {
+        let logger = MemoryLogger::new();
+        let mut game = get_game("dog", 6, Input::Timed(LineReader::spawn(BufReader::new(Stalled), false)), &logger);
+        game.set_deadline(Some(Countdown::new(Duration::from_millis(30), now_millis())));
+        game.play();
+        let expected = (GameStatus::Lost, true, 0);
+        let actual = (game.status(), game.is_out_of_time(), game.attempts());
+        assert_eq!(expected, actual);
+    }
+}
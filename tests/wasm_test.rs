@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use jogo_da_forca::constants::gibbet_phases::{GIBBET, HEAD};
+    use jogo_da_forca::wasm::wasm::{gibbet_stage, matches_pattern, WasmGame};
+
+    #[test]
+    fn new_game_hides_the_word_it_passes() {
+        let game = WasmGame::new("Dog", 6);
+        let expected: (String, u8, u8, String, Option<String>, String) = ("___".to_string(), 6, 0, "in_progress".to_string(), None, GIBBET.to_string());
+        let actual = (game.word(), game.lives_left(), game.stage(), game.status(), game.answer(), game.gibbet());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn guess_it_passes() {
+        let mut game = WasmGame::new("Dog", 6);
+        let expected: Vec<bool> = vec![true, false, false];
+        let actual: Vec<bool> = ["D", "z", ""].iter().map(|letter| game.guess(letter)).collect();
+        assert_eq!(expected, actual);
+        assert_eq!(("d__".to_string(), vec!["z".to_string()], HEAD.to_string()), (game.word(), game.misses(), game.gibbet()));
+    }
+
+    #[test]
+    fn guess_when_the_letter_was_guessed_before_costs_nothing_it_passes() {
+        let mut game = WasmGame::new("Dog", 6);
+        let expected: (Vec<bool>, u8, Vec<String>) = (vec![false, false, true, true], 5, vec!["z".to_string()]);
+        let actual: Vec<bool> = ["z", "Z", "d", "d"].iter().map(|letter| game.guess(letter)).collect();
+        assert_eq!(expected, (actual, game.lives_left(), game.misses()));
+    }
+
+    #[test]
+    fn guess_word_reveals_the_answer_it_passes() {
+        let mut game = WasmGame::new("Dog", 6);
+        assert!(game.guess_word("DOG"));
+        assert_eq!(("won".to_string(), Some("dog".to_string())), (game.status(), game.answer()));
+        assert!(!game.guess("d"));
+        assert_eq!(None, game.hint());
+    }
+
+    #[test]
+    fn random_game_it_passes() {
+        assert!(WasmGame::random("pt", 6).is_some());
+        assert!(WasmGame::random("xx", 6).is_none());
+    }
+
+    #[test]
+    fn helpers_it_passes() {
+        assert_eq!(3, gibbet_stage(4, 8));
+        assert!(matches_pattern("apple", "_pp__", ""));
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::sync::Arc;
+    use std::thread;
+    use jogo_da_forca::constants::gibbet_phases::{GIBBET, HEAD};
+    use jogo_da_forca::logger::sinks::MultiLogger;
+    use jogo_da_forca::random_word::word_list::{Word, WordFilter};
+    use jogo_da_forca::server::server::{ServerOptions, Session, DEFAULT_TURN_TIMEOUT, GREETING};
+    use jogo_da_forca::server::web::{serve_web, WebUpdate, INDEX_HTML};
+    use tiny_http::Server;
+    use tungstenite::{connect, Message, WebSocket};
+
+    fn get_options() -> ServerOptions {
+        let words = vec![Word::new("ab", "test", "en")];
+        ServerOptions { words: Arc::new(words), filter: WordFilter::default(), lives: 6, seed: Some(5), turn_timeout: DEFAULT_TURN_TIMEOUT }
+    }
+
+    fn start_server() -> SocketAddr {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve_web(server, get_options(), &mut MultiLogger::new()));
+        address
+    }
+
+    fn read_update<S: Read + Write>(socket: &mut WebSocket<S>) -> WebUpdate {
+        match socket.read().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            message => panic!("unexpected message {message:?}"),
+        }
+    }
+
+    #[test]
+    fn web_update_without_game_it_passes() {
+        let session = Session::new(get_options());
+        let expected = WebUpdate { reply: GREETING.to_string(), game: None, stage: 0, gibbet: GIBBET.to_string() };
+        let actual = WebUpdate::of(1, &session, GREETING.to_string());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn web_update_draws_the_stage_it_passes() {
+        let mut session = Session::new(get_options());
+        session.reply("NEW");
+        let reply = session.reply("GUESS z");
+        let actual = WebUpdate::of(1, &session, reply);
+        let expected: (&str, u8, &str, &str) = ("OK miss state word=__ misses=z lives=5/6", 1, HEAD, "__");
+        assert_eq!(expected, (actual.reply.as_str(), actual.stage, actual.gibbet.as_str(), actual.game.unwrap().word.as_str()));
+    }
+
+    #[test]
+    fn index_page_it_passes() {
+        let mut stream = TcpStream::connect(start_server()).unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("text/html"));
+        assert!(response.ends_with(INDEX_HTML));
+    }
+
+    #[test]
+    fn websocket_plays_a_game_it_passes() {
+        let (mut socket, _) = connect(format!("ws://{}/ws", start_server())).unwrap();
+        assert_eq!(GREETING, read_update(&mut socket).reply);
+        let mut actual: Vec<String> = Vec::new();
+        for line in ["NEW", "GUESS a", "WORD ab"] {
+            socket.send(Message::text(line)).unwrap();
+            actual.push(read_update(&mut socket).reply);
+        }
+        let expected = vec!["OK state word=__ misses= lives=6/6", "OK hit state word=a_ misses= lives=6/6", "OK correct result won word=ab"];
+        assert_eq!(expected, actual);
+    }
+}
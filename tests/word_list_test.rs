@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use jogo_da_forca::random_word::blocklist::{Blocklist, Blocklists};
+    use jogo_da_forca::random_word::random_word::get_random_word;
+    use jogo_da_forca::random_word::validate::{alphabet_of, validate_word_list, ValidationRules};
+    use jogo_da_forca::random_word::word_list::{built_in_words, choose_word, daily_seed, match_key, parse_word_list, Difficulty, Weighting, Word, WordFilter};
+
+    #[test]
+    fn difficulty_of_it_passes() {
+        assert_eq!(Difficulty::Easy, Difficulty::of("Dog"));
+        assert_eq!(Difficulty::Medium, Difficulty::of("Apple"));
+        assert_eq!(Difficulty::Hard, Difficulty::of("Elephant"));
+    }
+
+    #[test]
+    fn parse_word_list_it_passes() {
+        let content = "# comment\nApple,fruit\n\nDog\n";
+        let expected = vec![Word::new("Apple", "fruit", "en"), Word::new("Dog", "general", "en")];
+        let actual = parse_word_list(content, "en");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_word_list_weights_it_passes() {
+        let content = "cat,animal,5\ndog,animal,oops\n# weighting: log\ncow,animal,0\n# weighting: uniform\nemu,animal,40\n";
+        let expected = vec![5.0, 1.0, 0.0, 1.0];
+        let actual: Vec<f64> = parse_word_list(content, "en").iter().map(|word| word.weight).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn weighting_directive_it_passes() {
+        let expected = (Some(Ok(Weighting::Log)), Some(Err("unknown weighting `cubic` (expected linear, log or uniform)".to_string())), None);
+        let actual = (Weighting::directive("# weighting: LOG"), Weighting::directive("#weighting: cubic"), Weighting::directive("# animals"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn weighting_log_it_passes() {
+        let expected = (0.0, 1.0);
+        let actual = (Weighting::Log.apply(0.0), Weighting::Log.apply(std::f64::consts::E - 1.0));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn choose_word_without_weights_draws_like_before_it_passes() {
+        use rand::seq::SliceRandom;
+        let words = built_in_words();
+        let expected = words.choose(&mut StdRng::seed_from_u64(11)).cloned();
+        let actual = choose_word(&words, &WordFilter::default(), &mut StdRng::seed_from_u64(11)).cloned();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn choose_word_follows_the_weights_it_passes() {
+        let words = vec![Word::new("Cat", "animal", "en").with_weight(0.0), Word::new("Dog", "animal", "en").with_weight(3.0), Word::new("Cow", "animal", "en").with_weight(1.0)];
+        let mut rng = StdRng::seed_from_u64(3);
+        let drawn: Vec<String> = (0..400).filter_map(|_| choose_word(&words, &WordFilter::default(), &mut rng)).map(|word| word.text.clone()).collect();
+        let count = |text: &str| drawn.iter().filter(|word| word.as_str() == text).count();
+        let expected = (0, true);
+        let actual = (count("Cat"), count("Dog") > 2 * count("Cow"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn word_filter_matches_category_it_passes() {
+        let filter = WordFilter { category: Some("Animal".to_string()), ..WordFilter::default() };
+        let expected: bool = true;
+        let actual = filter.matches(&Word::new("Dog", "animal", "en"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn word_filter_does_not_match_other_language_it_passes() {
+        let filter = WordFilter { language: Some("pt".to_string()), ..WordFilter::default() };
+        let expected: bool = false;
+        let actual = filter.matches(&Word::new("Dog", "animal", "en"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn choose_word_when_seeded_is_deterministic_it_passes() {
+        let words = built_in_words();
+        let filter = WordFilter::default();
+        let expected = choose_word(&words, &filter, &mut StdRng::seed_from_u64(7)).cloned();
+        let actual = choose_word(&words, &filter, &mut StdRng::seed_from_u64(7)).cloned();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn choose_word_when_nothing_matches_is_none_it_passes() {
+        let words = built_in_words();
+        let filter = WordFilter { category: Some("planet".to_string()), ..WordFilter::default() };
+        let expected: bool = true;
+        let actual = choose_word(&words, &filter, &mut StdRng::seed_from_u64(7)).is_none();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    #[cfg(feature = "packs")]
+    fn built_in_words_have_packs_of_every_language_it_passes() {
+        let words = built_in_words();
+        let blocklists = Blocklists::family_friendly();
+        for language in ["en", "pt"] {
+            let pack: Vec<&Word> = words.iter().filter(|word| word.language == language).collect();
+            let mut categories: Vec<&str> = pack.iter().map(|word| word.category.as_str()).collect();
+            categories.dedup();
+            let content: String = pack.iter().map(|word| format!("{},{}\n", word.text, word.category)).collect();
+            let issues = validate_word_list(&content, &ValidationRules { blocklist: blocklists.get(language).cloned().unwrap_or_default(), ..ValidationRules::for_language(language) });
+            let expected = (true, true, 0);
+            let actual = (pack.len() >= 300, categories.len() >= 5, issues.len());
+            assert_eq!(expected, actual, "{language}: {issues:?}");
+        }
+    }
+
+    #[test]
+    fn get_random_word_is_a_built_in_word_it_passes() {
+        let word = get_random_word();
+        let expected: bool = true;
+        let actual = built_in_words().iter().any(|built_in| built_in.text == word);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn daily_seed_is_the_same_during_the_day_it_passes() {
+        let expected = daily_seed(86_400 * 3);
+        let actual = daily_seed(86_400 * 3 + 86_399);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_it_passes() {
+        let content = "apple,fruit\nApple,fruit\nno,general\nd0g,animal\n";
+        let issues = validate_word_list(content, &ValidationRules::default());
+        let actual: Vec<(usize, &str)> = issues.iter().map(|issue| (issue.line, issue.message.as_str())).collect();
+        let expected = vec![(2, "duplicate of `apple` on line 1"), (3, "too short (2 < 3)"), (4, "invalid character `0`")];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_duplicates_ignore_accents_it_passes() {
+        let content = "avó,família\navo,família\n";
+        let issues = validate_word_list(content, &ValidationRules::for_language("pt"));
+        let actual: Vec<(usize, &str, Option<&str>)> = issues.iter().map(|issue| (issue.line, issue.message.as_str(), issue.suggestion.as_deref())).collect();
+        let expected = vec![(2, "duplicate of `avó` on line 1", Some("remove the line"))];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_suggests_fitting_the_alphabet_it_passes() {
+        let content = "café,drink\nice-cream,food\n";
+        let issues = validate_word_list(content, &ValidationRules::for_language("en"));
+        let actual: Vec<(usize, &str, Option<&str>)> = issues.iter().map(|issue| (issue.line, issue.message.as_str(), issue.suggestion.as_deref())).collect();
+        let expected = vec![
+            (1, "invalid character `é`", Some("replace with `cafe`")),
+            (2, "invalid character `-`", Some("replace with `icecream`")),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_accepts_the_portuguese_alphabet_it_passes() {
+        let content = "coração,corpo\n";
+        let expected: bool = true;
+        let actual = validate_word_list(content, &ValidationRules::for_language("pt")).is_empty();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_lengths_it_passes() {
+        let rules = ValidationRules { min_length: 4, max_length: 6, ..ValidationRules::default() };
+        let issues = validate_word_list("dog,animal\nelephant,animal\n", &rules);
+        let actual: Vec<(usize, &str)> = issues.iter().map(|issue| (issue.line, issue.message.as_str())).collect();
+        let expected = vec![(1, "too short (3 < 4)"), (2, "too long (8 > 6)")];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_blocked_word_it_passes() {
+        let rules = ValidationRules { blocklist: Blocklist::parse("# blocked\ndarn\n"), ..ValidationRules::default() };
+        let issues = validate_word_list("Dárn,general\n", &rules);
+        let actual: Vec<(usize, &str)> = issues.iter().map(|issue| (issue.line, issue.message.as_str())).collect();
+        let expected = vec![(1, "blocked word")];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_missing_metadata_it_passes() {
+        let issues = validate_word_list("apple\n,fruit\n", &ValidationRules::default());
+        let actual: Vec<(usize, &str, Option<&str>)> = issues.iter().map(|issue| (issue.line, issue.message.as_str(), issue.suggestion.as_deref())).collect();
+        let expected = vec![
+            (1, "missing category (defaults to general)", Some("write `apple,general`")),
+            (2, "missing word", Some("remove the line")),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validate_word_list_weights_it_passes() {
+        let content = "# weighting: cubic\ncat,animal,-2\ndog,animal,3\n";
+        let issues = validate_word_list(content, &ValidationRules::default());
+        let actual: Vec<(usize, &str, Option<&str>)> = issues.iter().map(|issue| (issue.line, issue.message.as_str(), issue.suggestion.as_deref())).collect();
+        let expected = vec![
+            (1, "unknown weighting `cubic` (expected linear, log or uniform)", Some("write `# weighting: linear`, `log` or `uniform`")),
+            (2, "invalid weight `-2`", Some("write `cat,animal,1`")),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn blocklists_family_friendly_it_passes() {
+        let blocklists = Blocklists::family_friendly();
+        let expected = (false, false, true, true);
+        let actual = (
+            blocklists.allows(&Word::new("Merda", "geral", "pt")),
+            blocklists.allows(&Word::new("SHIT", "general", "en")),
+            blocklists.allows(&Word::new("Shit", "general", "xx")),
+            blocklists.allows(&Word::new("Apple", "fruit", "en")),
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn blocklists_add_is_per_language_it_passes() {
+        let mut blocklists = Blocklists::default();
+        blocklists.add("PT", Blocklist::parse("gato\n"));
+        let words = vec![Word::new("Gato", "animal", "pt"), Word::new("Gato", "animal", "es"), Word::new("Cão", "animal", "pt")];
+        let expected = vec![Word::new("Gato", "animal", "es"), Word::new("Cão", "animal", "pt")];
+        let actual = blocklists.filter(words);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn alphabet_of_unknown_language_is_none_it_passes() {
+        let expected = (Some("abcdefghijklmnopqrstuvwxyz"), None);
+        let actual = (alphabet_of("EN"), alphabet_of("xx"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn match_key_it_passes() {
+        let expected = "coracao";
+        let actual = match_key(" Coração ");
+        assert_eq!(expected, actual);
+    }
+}